@@ -22,6 +22,8 @@ export default function CreateCampaign() {
   const navigate = useNavigate();
   const { address, isConnected } = useWallet();
   const RPC_URL = "https://soroban-testnet.stellar.org:443";
+  // Platform contract whose admin can pause and upgrade campaigns (see .env.example)
+  const PLATFORM_CONTRACT_ID = import.meta.env.VITE_PLATFORM_CONTRACT_ID;
 
   const [formData, setFormData] = useState({
     title: "",
@@ -57,6 +59,12 @@ export default function CreateCampaign() {
       return;
     }
 
+    if (!PLATFORM_CONTRACT_ID) {
+      console.error("VITE_PLATFORM_CONTRACT_ID is not set");
+      alert("Campaign creation is not configured: set VITE_PLATFORM_CONTRACT_ID to the platform contract ID (see .env.example).");
+      return;
+    }
+
     try {
       // Validate inputs
       const goalXLM = parseFloat(formData.goal);
@@ -93,17 +101,33 @@ export default function CreateCampaign() {
       // XLM token address for testnet
       const xlmTokenAddress = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
 
-      // Note: Using old interface for now - update after deploying new contract
       const tx = await contract.initialize({
         owner: address,
         goal: goalStroops,
         deadline: deadlineTimestamp,
         xlm_token: xlmTokenAddress,
-        // TODO: Add these after contract update:
         title: formData.title,
         description: formData.description,
         image_url: formData.imageUrl,
         min_donation: minDonationStroops,
+        platform: PLATFORM_CONTRACT_ID,
+        // Optional features, all left off
+        features: {
+          vesting: { cliff: 0n, duration: 0n },
+          arbiter: undefined,
+          challenge_window: 0n,
+          withdrawal_delay: 0n,
+          revenue_sharing: false,
+          loan: { interest_bps: 0, installments: 0, interval: 0n },
+          presale_token: undefined,
+          presale_rate: 0n,
+          presale_amount: 0n,
+          sale_cap: 0n,
+          start_time: 0n,
+          refund_fallback: undefined,
+          refund_claim_period: 0n,
+          cool_off: 0n,
+        },
       });
 
       await submit(tx);
@@ -21,34 +21,238 @@ export class Client extends ContractClient {
         return ContractClient.deploy(null, options);
     }
     constructor(options) {
-        super(new ContractSpec(["AAAAAAAAAAAAAAAGZG9uYXRlAAAAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        super(new ContractSpec(["AAAAAQAAAAAAAAAAAAAABlJ1bGluZwAAAAAABAAAAAAAAAAHZG9uYXRlZAAAAAALAAAAAAAAAApyZWZ1bmRfYnBzAAAAAAAEAAAAAAAAAAtyZWZ1bmRfcG9vbAAAAAALAAAAAAAAAAtyZXNvbHZlZF9hdAAAAAAG",
+            "AAAAAQAAAAAAAAAAAAAAB0Rpc3B1dGUAAAAABAAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAhldmlkZW5jZQAAABAAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAAlvcGVuZWRfYXQAAAAAAAAG",
+            "AAAABQAAAAAAAAAAAAAACkFyYml0ZXJTZXQAAAAAAAEAAAALYXJiaXRlcl9zZXQAAAAAAgAAAAAAAAAHYXJiaXRlcgAAAAATAAAAAQAAAAAAAAAQY2hhbGxlbmdlX3dpbmRvdwAAAAYAAAAAAAAAAg==",
+            "AAAAAQAAAAAAAAAAAAAADUFyYml0ZXJDb25maWcAAAAAAAACAAAAAAAAAAdhcmJpdGVyAAAAABMAAAAAAAAAEGNoYWxsZW5nZV93aW5kb3cAAAAG",
+            "AAAABQAAAAAAAAAAAAAADURpc3B1dGVPcGVuZWQAAAAAAAABAAAADmRpc3B1dGVfb3BlbmVkAAAAAAADAAAAAAAAAAJpZAAAAAAABAAAAAEAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAEAAAAAAAAACGV2aWRlbmNlAAAAEAAAAAAAAAAC",
+            "AAAABQAAAAAAAAAAAAAADkRpc3B1dGVFeHBpcmVkAAAAAAABAAAAD2Rpc3B1dGVfZXhwaXJlZAAAAAABAAAAAAAAAAtyZWZ1bmRfcG9vbAAAAAALAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAD0Rpc3B1dGVSZXNvbHZlZAAAAAABAAAAEGRpc3B1dGVfcmVzb2x2ZWQAAAADAAAAAAAAAAdhcmJpdGVyAAAAABMAAAABAAAAAAAAAApyZWZ1bmRfYnBzAAAAAAAEAAAAAAAAAAAAAAALcmVmdW5kX3Bvb2wAAAAACwAAAAAAAAAC",
+            "AAAAAAAAAAAAAAAKZ2V0X3J1bGluZwAAAAAAAAAAAAEAAAPoAAAH0AAAAAZSdWxpbmcAAA==",
+            "AAAAAAAAAAAAAAALZ2V0X2FyYml0ZXIAAAAAAAAAAAEAAAPoAAAH0AAAAA1BcmJpdGVyQ29uZmlnAAAA",
+            "AAAAAAAAAAAAAAALZ2V0X2Rpc3B1dGUAAAAAAQAAAAAAAAACaWQAAAAAAAQAAAABAAAH0AAAAAdEaXNwdXRlAA==",
+            "AAAAAAAAAAAAAAAMb3Blbl9kaXNwdXRlAAAAAgAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAhldmlkZW5jZQAAABAAAAABAAAABA==",
+            "AAAAAAAAAAAAAAAOZXhwaXJlX2Rpc3B1dGUAAAAAAAAAAAABAAAACw==",
+            "AAAAAAAAAAAAAAAPcmVzb2x2ZV9kaXNwdXRlAAAAAAIAAAAAAAAAB2FyYml0ZXIAAAAAEwAAAAAAAAAKcmVmdW5kX2JwcwAAAAAABAAAAAA=",
+            "AAAAAAAAAAAAAAARZ2V0X2Rpc3B1dGVfY291bnQAAAAAAAAAAAAAAQAAAAQ=",
+            "AAAAAAAAAAAAAAASZ2V0X2Rpc3B1dGVfcmVmdW5kAAAAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
+            "AAAAAAAAAAAAAAAUY2xhaW1fZGlzcHV0ZV9yZWZ1bmQAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
+            "AAAAAAAAAAAAAAAVZ2V0X2FyYml0cmF0aW9uX3N0YXRlAAAAAAAAAAAAAAEAAAAE",
+            "AAAAAQAAAAAAAAAAAAAAEENhbXBhaWduRmVhdHVyZXMAAAAOAAAAAAAAAAdhcmJpdGVyAAAAA+gAAAATAAAAAAAAABBjaGFsbGVuZ2Vfd2luZG93AAAABgAAAAAAAAAIY29vbF9vZmYAAAAGAAAAAAAAAARsb2FuAAAH0AAAAAlMb2FuVGVybXMAAAAAAAAAAAAADnByZXNhbGVfYW1vdW50AAAAAAALAAAAAAAAAAxwcmVzYWxlX3JhdGUAAAALAAAAAAAAAA1wcmVzYWxlX3Rva2VuAAAAAAAD6AAAABMAAAAAAAAAE3JlZnVuZF9jbGFpbV9wZXJpb2QAAAAABgAAAAAAAAAPcmVmdW5kX2ZhbGxiYWNrAAAAA+gAAAATAAAAAAAAAA9yZXZlbnVlX3NoYXJpbmcAAAAAAQAAAAAAAAAIc2FsZV9jYXAAAAALAAAAAAAAAApzdGFydF90aW1lAAAAAAAGAAAAAAAAAAd2ZXN0aW5nAAAAB9AAAAAPVmVzdGluZ1NjaGVkdWxlAAAAAAAAAAAQd2l0aGRyYXdhbF9kZWxheQAAAAY=",
+            "AAAAAAAAAAAAAAAGZG9uYXRlAAAAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
             "AAAAAAAAAAAAAAAGcmVmdW5kAAAAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAAA",
             "AAAAAAAAAAAAAAAId2l0aGRyYXcAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAA",
-            "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAACAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAARnb2FsAAAACwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAAl4bG1fdG9rZW4AAAAAAAATAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACWltYWdlX3VybAAAAAAAABAAAAAAAAAADG1pbl9kb25hdGlvbgAAAAsAAAAA",
+            "AAAAAAAAAAAAAAAKZG9uYXRlX2ZvcgAAAAAAAwAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAAAAAA5jcmVkaXRlZF9kb25vcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+            "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAACgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAARnb2FsAAAACwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAAl4bG1fdG9rZW4AAAAAAAATAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACWltYWdlX3VybAAAAAAAABAAAAAAAAAADG1pbl9kb25hdGlvbgAAAAsAAAAAAAAACHBsYXRmb3JtAAAAEwAAAAAAAAAIZmVhdHVyZXMAAAfQAAAAEENhbXBhaWduRmVhdHVyZXMAAAAA",
             "AAAAAAAAAAAAAAAMZ2V0X2RvbmF0aW9uAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAs=",
-            "AAAAAAAAAAAAAAAOZ2V0X2FsbF9kb25vcnMAAAAAAAAAAAABAAAD6gAAA+0AAAACAAAAEwAAAAs=",
+            "AAAAAAAAAAAAAAAOZ2V0X2FsbF9kb25vcnMAAAAAAAAAAAABAAAD6gAAA+0AAAACAAAD6AAAABMAAAAL",
+            "AAAAAAAAAAAAAAAQZG9uYXRlX3dpdGhfbm90ZQAAAAUAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAdtZXNzYWdlAAAAA+gAAAAQAAAAAAAAAAxkaXNwbGF5X25hbWUAAAPoAAAAEAAAAAAAAAAJYW5vbnltb3VzAAAAAAAAAQAAAAA=",
             "AAAAAAAAAAAAAAAQZ2V0X21pbl9kb25hdGlvbgAAAAAAAAABAAAACw==",
             "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3JhaXNlZAAAAAAAAAABAAAACw==",
             "AAAAAAAAAAAAAAARZ2V0X2NhbXBhaWduX2luZm8AAAAAAAAAAAAAAQAAA+0AAAAIAAAAEwAAAAsAAAAGAAAAEAAAABAAAAAQAAAACwAAAAQ=",
             "AAAAAAAAAAAAAAASaXNfZGVhZGxpbmVfcGFzc2VkAAAAAAAAAAAAAQAAAAE=",
             "AAAAAAAAAAAAAAATZ2V0X2NhbXBhaWduX3N0YXR1cwAAAAAAAAAAAQAAAAQ=",
             "AAAAAAAAAAAAAAATZ2V0X2lzX2FscmVhZHlfaW5pdAAAAAAAAAAAAQAAAAE=",
-            "AAAAAAAAAAAAAAAXZ2V0X3Byb2dyZXNzX3BlcmNlbnRhZ2UAAAAAAAAAAAEAAAAE"]), options);
+            "AAAAAAAAAAAAAAAWZ2V0X3JlZnVuZF9kZXN0aW5hdGlvbgAAAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAABM=",
+            "AAAAAAAAAAAAAAAWc2V0X3JlZnVuZF9kZXN0aW5hdGlvbgAAAAAAAgAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAtkZXN0aW5hdGlvbgAAAAATAAAAAA==",
+            "AAAAAAAAAAAAAAAXZ2V0X3Byb2dyZXNzX3BlcmNlbnRhZ2UAAAAAAAAAAAEAAAAE",
+            "AAAABQAAAAAAAAAAAAAAFENhbXBhaWduUGF1c2VDaGFuZ2VkAAAAAQAAABZjYW1wYWlnbl9wYXVzZV9jaGFuZ2VkAAAAAAACAAAAAAAAAAlvcGVyYXRpb24AAAAAAAAEAAAAAQAAAAAAAAAGcGF1c2VkAAAAAAABAAAAAAAAAAI=",
+            "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAQAAAAAAAAAJb3BlcmF0aW9uAAAAAAAABAAAAAEAAAAB",
+            "AAAAAAAAAAAAAAAMZ2V0X3BsYXRmb3JtAAAAAAAAAAEAAAAT",
+            "AAAAAAAAAAAAAAATc2V0X2NhbXBhaWduX3BhdXNlZAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACW9wZXJhdGlvbgAAAAAAAAQAAAAAAAAABnBhdXNlZAAAAAAAAQAAAAA=",
+            "AAAAAQAAAAAAAAAAAAAABlBsZWRnZQAAAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAZzdGF0dXMAAAAAAAQ=",
+            "AAAAAAAAAAAAAAAGcGxlZGdlAAAAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+            "AAAAAAAAAAAAAAAHY29sbGVjdAAAAAABAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAABA==",
+            "AAAAAAAAAAAAAAAKZ2V0X3BsZWRnZQAAAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAB9AAAAAGUGxlZGdlAAA=",
+            "AAAAAAAAAAAAAAARZ2V0X3RvdGFsX3BsZWRnZWQAAAAAAAAAAAAAAQAAAAs=",
+            "AAAAAAAAAAAAAAATZ2V0X3RvdGFsX2RlZmF1bHRlZAAAAAAAAAAAAQAAAAs=",
+            "AAAABQAAAAAAAAAAAAAAC1dpbm5lckRyYXduAAAAAAEAAAAMd2lubmVyX2RyYXduAAAAAwAAAAAAAAAGd2lubmVyAAAAAAATAAAAAQAAAAAAAAAFcGxhY2UAAAAAAAAEAAAAAAAAAAAAAAAHZW50cmllcwAAAAAGAAAAAAAAAAI=",
+            "AAAAAAAAAAAAAAAMZHJhd193aW5uZXJzAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAVjb3VudAAAAAAAAAQAAAAAAAAAC3Blcl9taW5pbXVtAAAAAAEAAAABAAAD6gAAABM=",
+            "AAAAAAAAAAAAAAASZ2V0X3JhZmZsZV93aW5uZXJzAAAAAAAAAAAAAQAAA+oAAAAT",
+            "AAAAAQAAAAAAAAAAAAAADkRvbmF0aW9uUmVjb3JkAAAAAAAJAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACWFub255bW91cwAAAAAAAAEAAAAAAAAADGRpc3BsYXlfbmFtZQAAA+gAAAAQAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAAB21lc3NhZ2UAAAAD6AAAABAAAAAAAAAABXBheWVyAAAAAAAAEwAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+            "AAAAAQAAAAAAAAAAAAAADlB1YmxpY0RvbmF0aW9uAAAAAAAHAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAADGRpc3BsYXlfbmFtZQAAA+gAAAAQAAAAAAAAAAVkb25vcgAAAAAAA+gAAAATAAAAAAAAAAJpZAAAAAAABAAAAAAAAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAAdtZXNzYWdlAAAAA+gAAAAQAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAG",
+            "AAAAAAAAAAAAAAASZ2V0X2RvbmF0aW9uX2NvdW50AAAAAAAAAAAAAQAAAAQ=",
+            "AAAAAAAAAAAAAAATZ2V0X2RvbmF0aW9uX3JlY29yZAAAAAABAAAAAAAAAAJpZAAAAAAABAAAAAEAAAfQAAAADlB1YmxpY0RvbmF0aW9uAAA=",
+            "AAAAAAAAAAAAAAAUZ2V0X2RvbmF0aW9uX2hpc3RvcnkAAAACAAAAAAAAAAZvZmZzZXQAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAA5QdWJsaWNEb25hdGlvbgAA",
+            "AAAAAAAAAAAAAAAUZ2V0X3JlY2VudF9kb25hdGlvbnMAAAABAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAOUHVibGljRG9uYXRpb24AAA==",
+            "AAAAAAAAAAAAAAAWZ2V0X2RvbmF0aW9uc19ieV9kb25vcgAAAAAAAwAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAZvZmZzZXQAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAA5Eb25hdGlvblJlY29yZAAA",
+            "AAAAAQAAAAAAAAAAAAAACUxvYW5UZXJtcwAAAAAAAAMAAAAAAAAADGluc3RhbGxtZW50cwAAAAQAAAAAAAAADGludGVyZXN0X2JwcwAAAAQAAAAAAAAACGludGVydmFsAAAABg==",
+            "AAAAAQAAAAAAAAAAAAAACkxvYW5TdGF0dXMAAAAAAAgAAAAAAAAACWRlZmF1bHRlZAAAAAAAAAEAAAAAAAAAA2R1ZQAAAAALAAAAAAAAAAxpbnN0YWxsbWVudHMAAAAEAAAAAAAAAAxpbnRlcmVzdF9icHMAAAAEAAAAAAAAAAhpbnRlcnZhbAAAAAYAAAAAAAAABnJlcGFpZAAAAAAACwAAAAAAAAAFc3RhcnQAAAAAAAPoAAAABgAAAAAAAAAKdG90YWxfb3dlZAAAAAAACw==",
+            "AAAABQAAAAAAAAAAAAAACkxvYW5SZXBhaWQAAAAAAAEAAAALbG9hbl9yZXBhaWQAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAEFJlcGF5bWVudENsYWltZWQAAAABAAAAEXJlcGF5bWVudF9jbGFpbWVkAAAAAAAAAgAAAAAAAAAGbGVuZGVyAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+            "AAAAAAAAAAAAAAAFcmVwYXkAAAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+            "AAAAAAAAAAAAAAANY2hlY2tfZGVmYXVsdAAAAAAAAAAAAAABAAAABA==",
+            "AAAAAAAAAAAAAAAPY2xhaW1fcmVwYXltZW50AAAAAAEAAAAAAAAABmxlbmRlcgAAAAAAEwAAAAEAAAAL",
+            "AAAAAAAAAAAAAAAPZ2V0X2xvYW5fc3RhdHVzAAAAAAAAAAABAAAH0AAAAApMb2FuU3RhdHVzAAA=",
+            "AAAAAAAAAAAAAAAXZ2V0X2NsYWltYWJsZV9yZXBheW1lbnQAAAAAAQAAAAAAAAAGbGVuZGVyAAAAAAATAAAAAQAAAAs=",
+            "AAAAAQAAAAAAAAAAAAAAB1ByZXNhbGUAAAAAAwAAAAAAAAAIZXNjcm93ZWQAAAALAAAAAAAAAARyYXRlAAAACwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+            "AAAABQAAAAAAAAAAAAAADVRva2Vuc0NsYWltZWQAAAAAAAABAAAADnRva2Vuc19jbGFpbWVkAAAAAAADAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAAZyZWZ1bmQAAAAAAAsAAAAAAAAAAg==",
+            "AAAAAAAAAAAAAAALZ2V0X3ByZXNhbGUAAAAAAAAAAAEAAAfQAAAAB1ByZXNhbGUA",
+            "AAAAAAAAAAAAAAAMY2xhaW1fdG9rZW5zAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAs=",
+            "AAAAAAAAAAAAAAAMZ2V0X3NhbGVfY2FwAAAAAAAAAAEAAAPoAAAACw==",
+            "AAAAAAAAAAAAAAAOZ2V0X2FsbG9jYXRpb24AAAAAAAEAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAEAAAPtAAAAAgAAAAsAAAAL",
+            "AAAAAAAAAAAAAAAUZ2V0X2NsYWltYWJsZV90b2tlbnMAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
+            "AAAAAAAAAAAAAAAWcmVjbGFpbV9wcmVzYWxlX3Rva2VucwAAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAs=",
+            "AAAABQAAAAAAAAAAAAAAClJlZnVuZFBhaWQAAAAAAAEAAAALcmVmdW5kX3BhaWQAAAAAAwAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAALZGVzdGluYXRpb24AAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+            "AAAAAQAAAAAAAAAAAAAADFJlZnVuZFBvbGljeQAAAAIAAAAAAAAADGNsYWltX3BlcmlvZAAAAAYAAAAAAAAACGZhbGxiYWNrAAAAEw==",
+            "AAAABQAAAAAAAAAAAAAAE1VuY2xhaW1lZEZ1bmRzU3dlcHQAAAAAAQAAABV1bmNsYWltZWRfZnVuZHNfc3dlcHQAAAAAAAACAAAAAAAAAAhmYWxsYmFjawAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+            "AAAAAAAAAAAAAAALaXNfcmVmdW5kZWQAAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAE=",
+            "AAAAAAAAAAAAAAAPcHJvY2Vzc19yZWZ1bmRzAAAAAAEAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAAE",
+            "AAAAAAAAAAAAAAAPc3dlZXBfdW5jbGFpbWVkAAAAAAAAAAABAAAACw==",
+            "AAAAAAAAAAAAAAARZ2V0X3JlZnVuZF9jdXJzb3IAAAAAAAAAAAAAAQAAAAQ=",
+            "AAAAAAAAAAAAAAARZ2V0X3JlZnVuZF9wb2xpY3kAAAAAAAAAAAAAAQAAA+gAAAfQAAAADFJlZnVuZFBvbGljeQ==",
+            "AAAAAAAAAAAAAAAZZ2V0X3JlZnVuZF9jbGFpbV9kZWFkbGluZQAAAAAAAAAAAAABAAAD6AAAAAY=",
+            "AAAABQAAAAAAAAAAAAAADlJldmVudWVDbGFpbWVkAAAAAAABAAAAD3JldmVudWVfY2xhaW1lZAAAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+            "AAAABQAAAAAAAAAAAAAAEFJldmVudWVEZXBvc2l0ZWQAAAABAAAAEXJldmVudWVfZGVwb3NpdGVkAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+            "AAAAAAAAAAAAAAANY2xhaW1fcmV2ZW51ZQAAAAAAAAEAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAEAAAAL",
+            "AAAAAAAAAAAAAAAPZGVwb3NpdF9yZXZlbnVlAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+            "AAAAAAAAAAAAAAAQZ2V0X3JldmVudWVfaW5mbwAAAAAAAAABAAAD7QAAAAIAAAABAAAACw==",
+            "AAAAAAAAAAAAAAAVZ2V0X2NsYWltYWJsZV9yZXZlbnVlAAAAAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAs=",
+            "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAEgAAAAEAAAAAAAAACERvbmF0aW9uAAAAAQAAABMAAAABAAAAAAAAAAVEb25vcgAAAAAAAAEAAAAEAAAAAQAAAAAAAAAGUGxlZGdlAAAAAAABAAAAEwAAAAEAAAAAAAAAB1BsZWRnZXIAAAAAAQAAAAQAAAABAAAAAAAAAA5Eb25hdGlvblJlY29yZAAAAAAAAQAAAAQAAAABAAAAAAAAAAlBbm9ueW1vdXMAAAAAAAABAAAAEwAAAAEAAAAAAAAAEkRvbm9yRG9uYXRpb25Db3VudAAAAAAAAQAAABMAAAABAAAAAAAAAA1Eb25vckRvbmF0aW9uAAAAAAAAAgAAABMAAAAEAAAAAQAAAAAAAAAIUmVmdW5kVG8AAAABAAAAEwAAAAEAAAAAAAAADE1hdGNoaW5nUG9vbAAAAAEAAAAEAAAAAQAAAAAAAAAIUmVmdW5kZWQAAAABAAAAEwAAAAEAAAAAAAAADExhc3REb25hdGlvbgAAAAEAAAATAAAAAQAAAAAAAAAHRGlzcHV0ZQAAAAABAAAABAAAAAEAAAAAAAAAEVJldmVudWVDaGVja3BvaW50AAAAAAAAAQAAABMAAAABAAAAAAAAAA5Mb2FuQ2hlY2twb2ludAAAAAAAAQAAABMAAAABAAAAAAAAAA1Ub2tlbnNDbGFpbWVkAAAAAAAAAQAAABMAAAABAAAAAAAAAAhQcm9wb3NhbAAAAAEAAAAEAAAAAQAAAAAAAAAHTWF0Y2hlZAAAAAACAAAAEwAAAAQ=",
+            "AAAABQAAAAAAAAAAAAAAD1N0b3JhZ2VNaWdyYXRlZAAAAAABAAAAEHN0b3JhZ2VfbWlncmF0ZWQAAAACAAAAAAAAAAxmcm9tX3ZlcnNpb24AAAAEAAAAAAAAAAAAAAAKdG9fdmVyc2lvbgAAAAAABAAAAAAAAAAC",
+            "AAAABQAAAAAAAAAAAAAAEENvbnRyYWN0VXBncmFkZWQAAAABAAAAEWNvbnRyYWN0X3VwZ3JhZGVkAAAAAAAAAQAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAAAI=",
+            "AAAAAAAAAAAAAAAHbWlncmF0ZQAAAAAAAAAAAA==",
+            "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+            "AAAAAAAAAAAAAAASZ2V0X3NjaGVtYV92ZXJzaW9uAAAAAAAAAAAAAQAAAAQ=",
+            "AAAAAQAAAAAAAAAAAAAADVZlc3RpbmdTdGF0dXMAAAAAAAAHAAAAAAAAAAljbGFpbWFibGUAAAAAAAALAAAAAAAAAAdjbGFpbWVkAAAAAAsAAAAAAAAABWNsaWZmAAAAAAAABgAAAAAAAAAIZHVyYXRpb24AAAAGAAAAAAAAAAVzdGFydAAAAAAAA+gAAAAGAAAAAAAAAAV0b3RhbAAAAAAAAAsAAAAAAAAABnZlc3RlZAAAAAAACw==",
+            "AAAAAQAAAAAAAAAAAAAAD1Zlc3RpbmdTY2hlZHVsZQAAAAACAAAAAAAAAAVjbGlmZgAAAAAAAAYAAAAAAAAACGR1cmF0aW9uAAAABg==",
+            "AAAAAAAAAAAAAAAMY2xhaW1fdmVzdGVkAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAs=",
+            "AAAAAAAAAAAAAAASZ2V0X3Zlc3Rpbmdfc3RhdHVzAAAAAAAAAAAAAQAAB9AAAAANVmVzdGluZ1N0YXR1cwAAAA==",
+            "AAAAAQAAAAAAAAAAAAAADE1hdGNoaW5nUG9vbAAAAAYAAAAAAAAAA2NhcAAAAAALAAAAAAAAAAlkZXBvc2l0ZWQAAAAAAAALAAAAAAAAAAdtYXRjaGVkAAAAAAsAAAAAAAAACXJhdGlvX2JwcwAAAAAAAAQAAAAAAAAACHJldHVybmVkAAAAAQAAAAAAAAAHc3BvbnNvcgAAAAAT",
+            "AAAAAAAAAAAAAAANcmVjZWl2ZV9tYXRjaAAAAAAAAAIAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+            "AAAAAAAAAAAAAAAPcmV0dXJuX21hdGNoaW5nAAAAAAEAAAAAAAAAB3Bvb2xfaWQAAAAABAAAAAEAAAAL",
+            "AAAAAAAAAAAAAAARYWRkX21hdGNoaW5nX3Bvb2wAAAAAAAAEAAAAAAAAAAdzcG9uc29yAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAJcmF0aW9fYnBzAAAAAAAABAAAAAAAAAADY2FwAAAAAAsAAAABAAAABA==",
+            "AAAAAAAAAAAAAAARZ2V0X21hdGNoaW5nX3Bvb2wAAAAAAAABAAAAAAAAAAdwb29sX2lkAAAAAAQAAAABAAAH0AAAAAxNYXRjaGluZ1Bvb2w=",
+            "AAAAAAAAAAAAAAARZ2V0X3RvdGFsX21hdGNoZWQAAAAAAAAAAAAAAQAAAAs=",
+            "AAAAAAAAAAAAAAAXZ2V0X21hdGNoaW5nX3Bvb2xfY291bnQAAAAAAAAAAAEAAAAE",
+            "AAAAAQAAAAAAAAAAAAAACFByb3Bvc2FsAAAABQAAAAAAAAAGYWN0aW9uAAAAAAfQAAAADlByb3Bvc2FsQWN0aW9uAAAAAAAAAAAACWFwcHJvdmFscwAAAAAAA+oAAAATAAAAAAAAAAhleGVjdXRlZAAAAAEAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAACHByb3Bvc2VyAAAAEw==",
+            "AAAAAgAAAAAAAAAAAAAADlByb3Bvc2FsQWN0aW9uAAAAAAAFAAAAAQAAAAAAAAAIV2l0aGRyYXcAAAABAAAAEwAAAAEAAAAAAAAAClNldFNpZ25lcnMAAAAAAAIAAAPqAAAAEwAAAAQAAAABAAAAAAAAABFUcmFuc2Zlck93bmVyc2hpcAAAAAAAAAEAAAATAAAAAAAAAAAAAAATQ2FuY2VsT3duZXJUcmFuc2ZlcgAAAAABAAAAAAAAAAtEcmF3V2lubmVycwAAAAACAAAABAAAAAE=",
+            "AAAAAAAAAAAAAAALZ2V0X3NpZ25lcnMAAAAAAAAAAAEAAAPqAAAAEw==",
+            "AAAAAAAAAAAAAAALc2V0X3NpZ25lcnMAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzaWduZXJzAAAAA+oAAAATAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAEAAAAAA==",
+            "AAAAAAAAAAAAAAAMZ2V0X3Byb3Bvc2FsAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAEAAAfQAAAACFByb3Bvc2Fs",
+            "AAAAAAAAAAAAAAANZ2V0X3RocmVzaG9sZAAAAAAAAAAAAAABAAAABA==",
+            "AAAAAAAAAAAAAAAOYXBwcm92ZV9hY3Rpb24AAAAAAAIAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAA=",
+            "AAAAAAAAAAAAAAAOZXhlY3V0ZV9hY3Rpb24AAAAAAAIAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAA=",
+            "AAAAAAAAAAAAAAAOcHJvcG9zZV9hY3Rpb24AAAAAAAIAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAADlByb3Bvc2FsQWN0aW9uAAAAAAABAAAABA==",
+            "AAAAAAAAAAAAAAAOZ2V0X3N0YXJ0X3RpbWUAAAAAAAAAAAABAAAABg==",
+            "AAAABQAAAAAAAAAAAAAAEFdpdGhkcmF3YWxWZXRvZWQAAAABAAAAEXdpdGhkcmF3YWxfdmV0b2VkAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAAAAAAI=",
+            "AAAAAQAAAAAAAAAAAAAAEVBlbmRpbmdXaXRoZHJhd2FsAAAAAAAAAgAAAAAAAAAKcmVsZWFzZV9hdAAAAAAABgAAAAAAAAAMcmVxdWVzdGVkX2F0AAAABg==",
+            "AAAABQAAAAAAAAAAAAAAEldpdGhkcmF3YWxFeGVjdXRlZAAAAAAAAQAAABN3aXRoZHJhd2FsX2V4ZWN1dGVkAAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAC",
+            "AAAABQAAAAAAAAAAAAAAE1dpdGhkcmF3YWxSZXF1ZXN0ZWQAAAAAAQAAABR3aXRoZHJhd2FsX3JlcXVlc3RlZAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAACnJlbGVhc2VfYXQAAAAAAAYAAAAAAAAAAg==",
+            "AAAAAAAAAAAAAAAPdmV0b193aXRoZHJhd2FsAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAA==",
+            "AAAAAAAAAAAAAAASZXhlY3V0ZV93aXRoZHJhd2FsAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAA",
+            "AAAAAAAAAAAAAAAScmVxdWVzdF93aXRoZHJhd2FsAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAABg==",
+            "AAAAAAAAAAAAAAAUZ2V0X3dpdGhkcmF3YWxfZGVsYXkAAAAAAAAAAQAAAAY=",
+            "AAAAAAAAAAAAAAAUaXNfd2l0aGRyYXdhbF92ZXRvZWQAAAAAAAAAAQAAAAE=",
+            "AAAAAAAAAAAAAAAUbGlmdF93aXRoZHJhd2FsX3ZldG8AAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAA",
+            "AAAAAAAAAAAAAAAWZ2V0X3BlbmRpbmdfd2l0aGRyYXdhbAAAAAAAAAAAAAEAAAPoAAAH0AAAABFQZW5kaW5nV2l0aGRyYXdhbAAAAA==",
+            "AAAABQAAAAAAAAAAAAAAEURvbmF0aW9uV2l0aGRyYXduAAAAAAAAAQAAABJkb25hdGlvbl93aXRoZHJhd24AAAAAAAIAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+            "AAAAAAAAAAAAAAAIdW5wbGVkZ2UAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+            "AAAAAAAAAAAAAAAMZ2V0X2Nvb2xfb2ZmAAAAAAAAAAEAAAAG",
+            "AAAABQAAAAAAAAAAAAAAFE93bmVyc2hpcFRyYW5zZmVycmVkAAAAAQAAABVvd25lcnNoaXBfdHJhbnNmZXJyZWQAAAAAAAACAAAAAAAAAA5wcmV2aW91c19vd25lcgAAAAAAEwAAAAEAAAAAAAAACW5ld19vd25lcgAAAAAAABMAAAABAAAAAg==",
+            "AAAABQAAAAAAAAAAAAAAGU93bmVyc2hpcFRyYW5zZmVyUHJvcG9zZWQAAAAAAAABAAAAG293bmVyc2hpcF90cmFuc2Zlcl9wcm9wb3NlZAAAAAACAAAAAAAAAA1jdXJyZW50X293bmVyAAAAAAAAEwAAAAEAAAAAAAAADXBlbmRpbmdfb3duZXIAAAAAAAATAAAAAQAAAAI=",
+            "AAAABQAAAAAAAAAAAAAAGk93bmVyc2hpcFRyYW5zZmVyQ2FuY2VsbGVkAAAAAAABAAAAHG93bmVyc2hpcF90cmFuc2Zlcl9jYW5jZWxsZWQAAAACAAAAAAAAAA1jdXJyZW50X293bmVyAAAAAAAAEwAAAAEAAAAAAAAADXBlbmRpbmdfb3duZXIAAAAAAAATAAAAAQAAAAI=",
+            "AAAAAAAAAAAAAAAQYWNjZXB0X293bmVyc2hpcAAAAAEAAAAAAAAACW5ld19vd25lcgAAAAAAABMAAAAA",
+            "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfb3duZXIAAAAAAAAAAAAAAQAAA+gAAAAT",
+            "AAAAAAAAAAAAAAAVY2FuY2VsX293bmVyX3RyYW5zZmVyAAAAAAAAAQAAAAAAAAAHY3VycmVudAAAAAATAAAAAA==",
+            "AAAAAAAAAAAAAAAWcHJvcG9zZV9vd25lcl90cmFuc2ZlcgAAAAAAAgAAAAAAAAAHY3VycmVudAAAAAATAAAAAAAAAAluZXdfb3duZXIAAAAAAAATAAAAAA=="]), options);
         this.options = options;
     }
     fromJSON = {
+        get_ruling: (this.txFromJSON),
+        get_arbiter: (this.txFromJSON),
+        get_dispute: (this.txFromJSON),
+        open_dispute: (this.txFromJSON),
+        expire_dispute: (this.txFromJSON),
+        resolve_dispute: (this.txFromJSON),
+        get_dispute_count: (this.txFromJSON),
+        get_dispute_refund: (this.txFromJSON),
+        claim_dispute_refund: (this.txFromJSON),
+        get_arbitration_state: (this.txFromJSON),
         donate: (this.txFromJSON),
         refund: (this.txFromJSON),
         withdraw: (this.txFromJSON),
+        donate_for: (this.txFromJSON),
         initialize: (this.txFromJSON),
         get_donation: (this.txFromJSON),
         get_all_donors: (this.txFromJSON),
+        donate_with_note: (this.txFromJSON),
         get_min_donation: (this.txFromJSON),
         get_total_raised: (this.txFromJSON),
         get_campaign_info: (this.txFromJSON),
         is_deadline_passed: (this.txFromJSON),
         get_campaign_status: (this.txFromJSON),
         get_is_already_init: (this.txFromJSON),
-        get_progress_percentage: (this.txFromJSON)
+        get_refund_destination: (this.txFromJSON),
+        set_refund_destination: (this.txFromJSON),
+        get_progress_percentage: (this.txFromJSON),
+        is_paused: (this.txFromJSON),
+        get_platform: (this.txFromJSON),
+        set_campaign_paused: (this.txFromJSON),
+        pledge: (this.txFromJSON),
+        collect: (this.txFromJSON),
+        get_pledge: (this.txFromJSON),
+        get_total_pledged: (this.txFromJSON),
+        get_total_defaulted: (this.txFromJSON),
+        draw_winners: (this.txFromJSON),
+        get_raffle_winners: (this.txFromJSON),
+        get_donation_count: (this.txFromJSON),
+        get_donation_record: (this.txFromJSON),
+        get_donation_history: (this.txFromJSON),
+        get_recent_donations: (this.txFromJSON),
+        get_donations_by_donor: (this.txFromJSON),
+        repay: (this.txFromJSON),
+        check_default: (this.txFromJSON),
+        claim_repayment: (this.txFromJSON),
+        get_loan_status: (this.txFromJSON),
+        get_claimable_repayment: (this.txFromJSON),
+        get_presale: (this.txFromJSON),
+        claim_tokens: (this.txFromJSON),
+        get_sale_cap: (this.txFromJSON),
+        get_allocation: (this.txFromJSON),
+        get_claimable_tokens: (this.txFromJSON),
+        reclaim_presale_tokens: (this.txFromJSON),
+        is_refunded: (this.txFromJSON),
+        process_refunds: (this.txFromJSON),
+        sweep_unclaimed: (this.txFromJSON),
+        get_refund_cursor: (this.txFromJSON),
+        get_refund_policy: (this.txFromJSON),
+        get_refund_claim_deadline: (this.txFromJSON),
+        claim_revenue: (this.txFromJSON),
+        deposit_revenue: (this.txFromJSON),
+        get_revenue_info: (this.txFromJSON),
+        get_claimable_revenue: (this.txFromJSON),
+        migrate: (this.txFromJSON),
+        upgrade: (this.txFromJSON),
+        get_schema_version: (this.txFromJSON),
+        claim_vested: (this.txFromJSON),
+        get_vesting_status: (this.txFromJSON),
+        receive_match: (this.txFromJSON),
+        return_matching: (this.txFromJSON),
+        add_matching_pool: (this.txFromJSON),
+        get_matching_pool: (this.txFromJSON),
+        get_total_matched: (this.txFromJSON),
+        get_matching_pool_count: (this.txFromJSON),
+        get_signers: (this.txFromJSON),
+        set_signers: (this.txFromJSON),
+        get_proposal: (this.txFromJSON),
+        get_threshold: (this.txFromJSON),
+        approve_action: (this.txFromJSON),
+        execute_action: (this.txFromJSON),
+        propose_action: (this.txFromJSON),
+        get_start_time: (this.txFromJSON),
+        veto_withdrawal: (this.txFromJSON),
+        execute_withdrawal: (this.txFromJSON),
+        request_withdrawal: (this.txFromJSON),
+        get_withdrawal_delay: (this.txFromJSON),
+        is_withdrawal_vetoed: (this.txFromJSON),
+        lift_withdrawal_veto: (this.txFromJSON),
+        get_pending_withdrawal: (this.txFromJSON),
+        unpledge: (this.txFromJSON),
+        get_cool_off: (this.txFromJSON),
+        accept_ownership: (this.txFromJSON),
+        get_pending_owner: (this.txFromJSON),
+        cancel_owner_transfer: (this.txFromJSON),
+        propose_owner_transfer: (this.txFromJSON)
     };
 }
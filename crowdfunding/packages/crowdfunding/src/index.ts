@@ -39,7 +39,339 @@ export const networks = {
 } as const
 
 
+export interface Ruling {
+  donated: i128;
+  refund_bps: u32;
+  refund_pool: i128;
+  resolved_at: u64;
+}
+
+export interface Dispute {
+  donor: string;
+  evidence: string;
+  id: u32;
+  opened_at: u64;
+}
+
+export interface ArbiterConfig {
+  arbiter: string;
+  challenge_window: u64;
+}
+
+export interface CampaignFeatures {
+  arbiter: Option<string>;
+  challenge_window: u64;
+  cool_off: u64;
+  loan: LoanTerms;
+  presale_amount: i128;
+  presale_rate: i128;
+  presale_token: Option<string>;
+  refund_claim_period: u64;
+  refund_fallback: Option<string>;
+  revenue_sharing: boolean;
+  sale_cap: i128;
+  start_time: u64;
+  vesting: VestingSchedule;
+  withdrawal_delay: u64;
+}
+
+export interface Pledge {
+  amount: i128;
+  status: u32;
+}
+
+export interface DonationRecord {
+  amount: i128;
+  anonymous: boolean;
+  display_name: Option<string>;
+  donor: string;
+  id: u32;
+  ledger: u32;
+  message: Option<string>;
+  payer: string;
+  timestamp: u64;
+}
+
+export interface PublicDonation {
+  amount: i128;
+  display_name: Option<string>;
+  donor: Option<string>;
+  id: u32;
+  ledger: u32;
+  message: Option<string>;
+  timestamp: u64;
+}
+
+export interface LoanTerms {
+  installments: u32;
+  interest_bps: u32;
+  interval: u64;
+}
+
+export interface LoanStatus {
+  defaulted: boolean;
+  due: i128;
+  installments: u32;
+  interest_bps: u32;
+  interval: u64;
+  repaid: i128;
+  start: Option<u64>;
+  total_owed: i128;
+}
+
+export interface Presale {
+  escrowed: i128;
+  rate: i128;
+  token: string;
+}
+
+export interface RefundPolicy {
+  claim_period: u64;
+  fallback: string;
+}
+
+export type DataKey = {tag: "Donation", values: readonly [string]} | {tag: "Donor", values: readonly [u32]} | {tag: "Pledge", values: readonly [string]} | {tag: "Pledger", values: readonly [u32]} | {tag: "DonationRecord", values: readonly [u32]} | {tag: "Anonymous", values: readonly [string]} | {tag: "DonorDonationCount", values: readonly [string]} | {tag: "DonorDonation", values: readonly [string, u32]} | {tag: "RefundTo", values: readonly [string]} | {tag: "MatchingPool", values: readonly [u32]} | {tag: "Refunded", values: readonly [string]} | {tag: "LastDonation", values: readonly [string]} | {tag: "Dispute", values: readonly [u32]} | {tag: "RevenueCheckpoint", values: readonly [string]} | {tag: "LoanCheckpoint", values: readonly [string]} | {tag: "TokensClaimed", values: readonly [string]} | {tag: "Proposal", values: readonly [u32]} | {tag: "Matched", values: readonly [string, u32]};
+
+export interface VestingStatus {
+  claimable: i128;
+  claimed: i128;
+  cliff: u64;
+  duration: u64;
+  start: Option<u64>;
+  total: i128;
+  vested: i128;
+}
+
+export interface VestingSchedule {
+  cliff: u64;
+  duration: u64;
+}
+
+export interface MatchingPool {
+  cap: i128;
+  deposited: i128;
+  matched: i128;
+  ratio_bps: u32;
+  returned: boolean;
+  sponsor: string;
+}
+
+export interface Proposal {
+  action: ProposalAction;
+  approvals: Array<string>;
+  executed: boolean;
+  expires_at: u64;
+  proposer: string;
+}
+
+export type ProposalAction = {tag: "Withdraw", values: readonly [string]} | {tag: "SetSigners", values: readonly [Array<string>, u32]} | {tag: "TransferOwnership", values: readonly [string]} | {tag: "CancelOwnerTransfer", values: void} | {tag: "DrawWinners", values: readonly [u32, boolean]};
+
+export interface PendingWithdrawal {
+  release_at: u64;
+  requested_at: u64;
+}
+
 export interface Client {
+  /**
+   * Construct and simulate a get_ruling transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_ruling: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<Ruling>>>
+
+  /**
+   * Construct and simulate a get_arbiter transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_arbiter: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<ArbiterConfig>>>
+
+  /**
+   * Construct and simulate a get_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_dispute: ({id}: {id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Dispute>>
+
+  /**
+   * Construct and simulate a open_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  open_dispute: ({donor, evidence}: {donor: string, evidence: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a expire_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  expire_dispute: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a resolve_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  resolve_dispute: ({arbiter, refund_bps}: {arbiter: string, refund_bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_dispute_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_dispute_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_dispute_refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_dispute_refund: ({donor}: {donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a claim_dispute_refund transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_dispute_refund: ({donor}: {donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_arbitration_state transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_arbitration_state: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
   /**
    * Construct and simulate a donate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
@@ -100,10 +432,30 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a donate_for transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  donate_for: ({payer, credited_donor, amount}: {payer: string, credited_donor: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
   /**
    * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  initialize: ({owner, goal, deadline, xlm_token, title, description, image_url, min_donation}: {owner: string, goal: i128, deadline: u64, xlm_token: string, title: string, description: string, image_url: string, min_donation: i128}, options?: {
+  initialize: ({owner, goal, deadline, xlm_token, title, description, image_url, min_donation, platform, features}: {owner: string, goal: i128, deadline: u64, xlm_token: string, title: string, description: string, image_url: string, min_donation: i128, platform: string, features: CampaignFeatures}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -158,7 +510,27 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<readonly [string, i128]>>>
+  }) => Promise<AssembledTransaction<Array<readonly [Option<string>, i128]>>>
+
+  /**
+   * Construct and simulate a donate_with_note transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  donate_with_note: ({donor, amount, message, display_name, anonymous}: {donor: string, amount: i128, message: Option<string>, display_name: Option<string>, anonymous: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
 
   /**
    * Construct and simulate a get_min_donation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -281,9 +653,9 @@ export interface Client {
   }) => Promise<AssembledTransaction<boolean>>
 
   /**
-   * Construct and simulate a get_progress_percentage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Construct and simulate a get_refund_destination transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
-  get_progress_percentage: (options?: {
+  get_refund_destination: ({donor}: {donor: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -298,7 +670,1407 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u32>>
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_refund_destination transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_refund_destination: ({donor, destination}: {donor: string, destination: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_progress_percentage transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_progress_percentage: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_paused: ({operation}: {operation: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_platform transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_platform: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_campaign_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_campaign_paused: ({admin, operation, paused}: {admin: string, operation: u32, paused: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a pledge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  pledge: ({donor, amount}: {donor: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a collect transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  collect: ({limit}: {limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_pledge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pledge: ({donor}: {donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Pledge>>
+
+  /**
+   * Construct and simulate a get_total_pledged transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_total_pledged: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_total_defaulted transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_total_defaulted: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a draw_winners transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  draw_winners: ({owner, count, per_minimum}: {owner: string, count: u32, per_minimum: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a get_raffle_winners transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_raffle_winners: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a get_donation_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_donation_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_donation_record transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_donation_record: ({id}: {id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<PublicDonation>>
+
+  /**
+   * Construct and simulate a get_donation_history transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_donation_history: ({offset, limit}: {offset: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<PublicDonation>>>
+
+  /**
+   * Construct and simulate a get_recent_donations transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_recent_donations: ({limit}: {limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<PublicDonation>>>
+
+  /**
+   * Construct and simulate a get_donations_by_donor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_donations_by_donor: ({donor, offset, limit}: {donor: string, offset: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<DonationRecord>>>
+
+  /**
+   * Construct and simulate a repay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  repay: ({owner, amount}: {owner: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a check_default transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  check_default: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a claim_repayment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_repayment: ({lender}: {lender: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_loan_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_loan_status: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<LoanStatus>>
+
+  /**
+   * Construct and simulate a get_claimable_repayment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_claimable_repayment: ({lender}: {lender: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_presale transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_presale: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Presale>>
+
+  /**
+   * Construct and simulate a claim_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_tokens: ({donor}: {donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_sale_cap transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_sale_cap: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<i128>>>
+
+  /**
+   * Construct and simulate a get_allocation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_allocation: ({donor}: {donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [i128, i128]>>
+
+  /**
+   * Construct and simulate a get_claimable_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_claimable_tokens: ({donor}: {donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a reclaim_presale_tokens transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  reclaim_presale_tokens: ({owner}: {owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a is_refunded transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_refunded: ({donor}: {donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a process_refunds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  process_refunds: ({limit}: {limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a sweep_unclaimed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  sweep_unclaimed: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_refund_cursor transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_refund_cursor: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_refund_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_refund_policy: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<RefundPolicy>>>
+
+  /**
+   * Construct and simulate a get_refund_claim_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_refund_claim_deadline: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<u64>>>
+
+  /**
+   * Construct and simulate a claim_revenue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_revenue: ({donor}: {donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a deposit_revenue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  deposit_revenue: ({owner, amount}: {owner: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_revenue_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_revenue_info: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [boolean, i128]>>
+
+  /**
+   * Construct and simulate a get_claimable_revenue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_claimable_revenue: ({donor}: {donor: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a migrate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  migrate: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  upgrade: ({admin, new_wasm_hash}: {admin: string, new_wasm_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_schema_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_schema_version: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a claim_vested transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  claim_vested: ({owner}: {owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_vesting_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_vesting_status: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<VestingStatus>>
+
+  /**
+   * Construct and simulate a receive_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  receive_match: ({funder, amount}: {funder: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a return_matching transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  return_matching: ({pool_id}: {pool_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a add_matching_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  add_matching_pool: ({sponsor, amount, ratio_bps, cap}: {sponsor: string, amount: i128, ratio_bps: u32, cap: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_matching_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_matching_pool: ({pool_id}: {pool_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<MatchingPool>>
+
+  /**
+   * Construct and simulate a get_total_matched transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_total_matched: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_matching_pool_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_matching_pool_count: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_signers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_signers: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<string>>>
+
+  /**
+   * Construct and simulate a set_signers transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  set_signers: ({owner, signers, threshold}: {owner: string, signers: Array<string>, threshold: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_proposal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_proposal: ({proposal_id}: {proposal_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Proposal>>
+
+  /**
+   * Construct and simulate a get_threshold transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_threshold: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a approve_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  approve_action: ({signer, proposal_id}: {signer: string, proposal_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a execute_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  execute_action: ({signer, proposal_id}: {signer: string, proposal_id: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a propose_action transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  propose_action: ({proposer, action}: {proposer: string, action: ProposalAction}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_start_time transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_start_time: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a veto_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  veto_withdrawal: ({admin, reason}: {admin: string, reason: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a execute_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  execute_withdrawal: ({owner}: {owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a request_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  request_withdrawal: ({owner}: {owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_withdrawal_delay transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_withdrawal_delay: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a is_withdrawal_vetoed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_withdrawal_vetoed: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a lift_withdrawal_veto transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  lift_withdrawal_veto: ({admin}: {admin: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_pending_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pending_withdrawal: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<PendingWithdrawal>>>
+
+  /**
+   * Construct and simulate a unpledge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  unpledge: ({donor, amount}: {donor: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_cool_off transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_cool_off: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a accept_ownership transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  accept_ownership: ({new_owner}: {new_owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_pending_owner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_pending_owner: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a cancel_owner_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  cancel_owner_transfer: ({current}: {current: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a propose_owner_transfer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  propose_owner_transfer: ({current, new_owner}: {current: string, new_owner: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -318,35 +2090,239 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAAAAAAAAAAAGZG9uYXRlAAAAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABlJ1bGluZwAAAAAABAAAAAAAAAAHZG9uYXRlZAAAAAALAAAAAAAAAApyZWZ1bmRfYnBzAAAAAAAEAAAAAAAAAAtyZWZ1bmRfcG9vbAAAAAALAAAAAAAAAAtyZXNvbHZlZF9hdAAAAAAG",
+        "AAAAAQAAAAAAAAAAAAAAB0Rpc3B1dGUAAAAABAAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAhldmlkZW5jZQAAABAAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAAlvcGVuZWRfYXQAAAAAAAAG",
+        "AAAABQAAAAAAAAAAAAAACkFyYml0ZXJTZXQAAAAAAAEAAAALYXJiaXRlcl9zZXQAAAAAAgAAAAAAAAAHYXJiaXRlcgAAAAATAAAAAQAAAAAAAAAQY2hhbGxlbmdlX3dpbmRvdwAAAAYAAAAAAAAAAg==",
+        "AAAAAQAAAAAAAAAAAAAADUFyYml0ZXJDb25maWcAAAAAAAACAAAAAAAAAAdhcmJpdGVyAAAAABMAAAAAAAAAEGNoYWxsZW5nZV93aW5kb3cAAAAG",
+        "AAAABQAAAAAAAAAAAAAADURpc3B1dGVPcGVuZWQAAAAAAAABAAAADmRpc3B1dGVfb3BlbmVkAAAAAAADAAAAAAAAAAJpZAAAAAAABAAAAAEAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAEAAAAAAAAACGV2aWRlbmNlAAAAEAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADkRpc3B1dGVFeHBpcmVkAAAAAAABAAAAD2Rpc3B1dGVfZXhwaXJlZAAAAAABAAAAAAAAAAtyZWZ1bmRfcG9vbAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAD0Rpc3B1dGVSZXNvbHZlZAAAAAABAAAAEGRpc3B1dGVfcmVzb2x2ZWQAAAADAAAAAAAAAAdhcmJpdGVyAAAAABMAAAABAAAAAAAAAApyZWZ1bmRfYnBzAAAAAAAEAAAAAAAAAAAAAAALcmVmdW5kX3Bvb2wAAAAACwAAAAAAAAAC",
+        "AAAAAAAAAAAAAAAKZ2V0X3J1bGluZwAAAAAAAAAAAAEAAAPoAAAH0AAAAAZSdWxpbmcAAA==",
+        "AAAAAAAAAAAAAAALZ2V0X2FyYml0ZXIAAAAAAAAAAAEAAAPoAAAH0AAAAA1BcmJpdGVyQ29uZmlnAAAA",
+        "AAAAAAAAAAAAAAALZ2V0X2Rpc3B1dGUAAAAAAQAAAAAAAAACaWQAAAAAAAQAAAABAAAH0AAAAAdEaXNwdXRlAA==",
+        "AAAAAAAAAAAAAAAMb3Blbl9kaXNwdXRlAAAAAgAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAhldmlkZW5jZQAAABAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAOZXhwaXJlX2Rpc3B1dGUAAAAAAAAAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAPcmVzb2x2ZV9kaXNwdXRlAAAAAAIAAAAAAAAAB2FyYml0ZXIAAAAAEwAAAAAAAAAKcmVmdW5kX2JwcwAAAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAARZ2V0X2Rpc3B1dGVfY291bnQAAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAASZ2V0X2Rpc3B1dGVfcmVmdW5kAAAAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAUY2xhaW1fZGlzcHV0ZV9yZWZ1bmQAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAVZ2V0X2FyYml0cmF0aW9uX3N0YXRlAAAAAAAAAAAAAAEAAAAE",
+        "AAAAAQAAAAAAAAAAAAAAEENhbXBhaWduRmVhdHVyZXMAAAAOAAAAAAAAAAdhcmJpdGVyAAAAA+gAAAATAAAAAAAAABBjaGFsbGVuZ2Vfd2luZG93AAAABgAAAAAAAAAIY29vbF9vZmYAAAAGAAAAAAAAAARsb2FuAAAH0AAAAAlMb2FuVGVybXMAAAAAAAAAAAAADnByZXNhbGVfYW1vdW50AAAAAAALAAAAAAAAAAxwcmVzYWxlX3JhdGUAAAALAAAAAAAAAA1wcmVzYWxlX3Rva2VuAAAAAAAD6AAAABMAAAAAAAAAE3JlZnVuZF9jbGFpbV9wZXJpb2QAAAAABgAAAAAAAAAPcmVmdW5kX2ZhbGxiYWNrAAAAA+gAAAATAAAAAAAAAA9yZXZlbnVlX3NoYXJpbmcAAAAAAQAAAAAAAAAIc2FsZV9jYXAAAAALAAAAAAAAAApzdGFydF90aW1lAAAAAAAGAAAAAAAAAAd2ZXN0aW5nAAAAB9AAAAAPVmVzdGluZ1NjaGVkdWxlAAAAAAAAAAAQd2l0aGRyYXdhbF9kZWxheQAAAAY=",
+        "AAAAAAAAAAAAAAAGZG9uYXRlAAAAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
         "AAAAAAAAAAAAAAAGcmVmdW5kAAAAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAAA",
         "AAAAAAAAAAAAAAAId2l0aGRyYXcAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAA",
-        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAACAAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAARnb2FsAAAACwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAAl4bG1fdG9rZW4AAAAAAAATAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACWltYWdlX3VybAAAAAAAABAAAAAAAAAADG1pbl9kb25hdGlvbgAAAAsAAAAA",
+        "AAAAAAAAAAAAAAAKZG9uYXRlX2ZvcgAAAAAAAwAAAAAAAAAFcGF5ZXIAAAAAAAATAAAAAAAAAA5jcmVkaXRlZF9kb25vcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAAAAAAAKaW5pdGlhbGl6ZQAAAAAACgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAARnb2FsAAAACwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAAl4bG1fdG9rZW4AAAAAAAATAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAACWltYWdlX3VybAAAAAAAABAAAAAAAAAADG1pbl9kb25hdGlvbgAAAAsAAAAAAAAACHBsYXRmb3JtAAAAEwAAAAAAAAAIZmVhdHVyZXMAAAfQAAAAEENhbXBhaWduRmVhdHVyZXMAAAAA",
         "AAAAAAAAAAAAAAAMZ2V0X2RvbmF0aW9uAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAs=",
-        "AAAAAAAAAAAAAAAOZ2V0X2FsbF9kb25vcnMAAAAAAAAAAAABAAAD6gAAA+0AAAACAAAAEwAAAAs=",
+        "AAAAAAAAAAAAAAAOZ2V0X2FsbF9kb25vcnMAAAAAAAAAAAABAAAD6gAAA+0AAAACAAAD6AAAABMAAAAL",
+        "AAAAAAAAAAAAAAAQZG9uYXRlX3dpdGhfbm90ZQAAAAUAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAdtZXNzYWdlAAAAA+gAAAAQAAAAAAAAAAxkaXNwbGF5X25hbWUAAAPoAAAAEAAAAAAAAAAJYW5vbnltb3VzAAAAAAAAAQAAAAA=",
         "AAAAAAAAAAAAAAAQZ2V0X21pbl9kb25hdGlvbgAAAAAAAAABAAAACw==",
         "AAAAAAAAAAAAAAAQZ2V0X3RvdGFsX3JhaXNlZAAAAAAAAAABAAAACw==",
         "AAAAAAAAAAAAAAARZ2V0X2NhbXBhaWduX2luZm8AAAAAAAAAAAAAAQAAA+0AAAAIAAAAEwAAAAsAAAAGAAAAEAAAABAAAAAQAAAACwAAAAQ=",
         "AAAAAAAAAAAAAAASaXNfZGVhZGxpbmVfcGFzc2VkAAAAAAAAAAAAAQAAAAE=",
         "AAAAAAAAAAAAAAATZ2V0X2NhbXBhaWduX3N0YXR1cwAAAAAAAAAAAQAAAAQ=",
         "AAAAAAAAAAAAAAATZ2V0X2lzX2FscmVhZHlfaW5pdAAAAAAAAAAAAQAAAAE=",
-        "AAAAAAAAAAAAAAAXZ2V0X3Byb2dyZXNzX3BlcmNlbnRhZ2UAAAAAAAAAAAEAAAAE" ]),
+        "AAAAAAAAAAAAAAAWZ2V0X3JlZnVuZF9kZXN0aW5hdGlvbgAAAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAABM=",
+        "AAAAAAAAAAAAAAAWc2V0X3JlZnVuZF9kZXN0aW5hdGlvbgAAAAAAAgAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAtkZXN0aW5hdGlvbgAAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAAXZ2V0X3Byb2dyZXNzX3BlcmNlbnRhZ2UAAAAAAAAAAAEAAAAE",
+        "AAAABQAAAAAAAAAAAAAAFENhbXBhaWduUGF1c2VDaGFuZ2VkAAAAAQAAABZjYW1wYWlnbl9wYXVzZV9jaGFuZ2VkAAAAAAACAAAAAAAAAAlvcGVyYXRpb24AAAAAAAAEAAAAAQAAAAAAAAAGcGF1c2VkAAAAAAABAAAAAAAAAAI=",
+        "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAQAAAAAAAAAJb3BlcmF0aW9uAAAAAAAABAAAAAEAAAAB",
+        "AAAAAAAAAAAAAAAMZ2V0X3BsYXRmb3JtAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAATc2V0X2NhbXBhaWduX3BhdXNlZAAAAAADAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAACW9wZXJhdGlvbgAAAAAAAAQAAAAAAAAABnBhdXNlZAAAAAAAAQAAAAA=",
+        "AAAAAQAAAAAAAAAAAAAABlBsZWRnZQAAAAAAAgAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAZzdGF0dXMAAAAAAAQ=",
+        "AAAAAAAAAAAAAAAGcGxlZGdlAAAAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAAHY29sbGVjdAAAAAABAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAKZ2V0X3BsZWRnZQAAAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAB9AAAAAGUGxlZGdlAAA=",
+        "AAAAAAAAAAAAAAARZ2V0X3RvdGFsX3BsZWRnZWQAAAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAATZ2V0X3RvdGFsX2RlZmF1bHRlZAAAAAAAAAAAAQAAAAs=",
+        "AAAABQAAAAAAAAAAAAAAC1dpbm5lckRyYXduAAAAAAEAAAAMd2lubmVyX2RyYXduAAAAAwAAAAAAAAAGd2lubmVyAAAAAAATAAAAAQAAAAAAAAAFcGxhY2UAAAAAAAAEAAAAAAAAAAAAAAAHZW50cmllcwAAAAAGAAAAAAAAAAI=",
+        "AAAAAAAAAAAAAAAMZHJhd193aW5uZXJzAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAVjb3VudAAAAAAAAAQAAAAAAAAAC3Blcl9taW5pbXVtAAAAAAEAAAABAAAD6gAAABM=",
+        "AAAAAAAAAAAAAAASZ2V0X3JhZmZsZV93aW5uZXJzAAAAAAAAAAAAAQAAA+oAAAAT",
+        "AAAAAQAAAAAAAAAAAAAADkRvbmF0aW9uUmVjb3JkAAAAAAAJAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAACWFub255bW91cwAAAAAAAAEAAAAAAAAADGRpc3BsYXlfbmFtZQAAA+gAAAAQAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAAAmlkAAAAAAAEAAAAAAAAAAZsZWRnZXIAAAAAAAQAAAAAAAAAB21lc3NhZ2UAAAAD6AAAABAAAAAAAAAABXBheWVyAAAAAAAAEwAAAAAAAAAJdGltZXN0YW1wAAAAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAADlB1YmxpY0RvbmF0aW9uAAAAAAAHAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAADGRpc3BsYXlfbmFtZQAAA+gAAAAQAAAAAAAAAAVkb25vcgAAAAAAA+gAAAATAAAAAAAAAAJpZAAAAAAABAAAAAAAAAAGbGVkZ2VyAAAAAAAEAAAAAAAAAAdtZXNzYWdlAAAAA+gAAAAQAAAAAAAAAAl0aW1lc3RhbXAAAAAAAAAG",
+        "AAAAAAAAAAAAAAASZ2V0X2RvbmF0aW9uX2NvdW50AAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAATZ2V0X2RvbmF0aW9uX3JlY29yZAAAAAABAAAAAAAAAAJpZAAAAAAABAAAAAEAAAfQAAAADlB1YmxpY0RvbmF0aW9uAAA=",
+        "AAAAAAAAAAAAAAAUZ2V0X2RvbmF0aW9uX2hpc3RvcnkAAAACAAAAAAAAAAZvZmZzZXQAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAA5QdWJsaWNEb25hdGlvbgAA",
+        "AAAAAAAAAAAAAAAUZ2V0X3JlY2VudF9kb25hdGlvbnMAAAABAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAAOUHVibGljRG9uYXRpb24AAA==",
+        "AAAAAAAAAAAAAAAWZ2V0X2RvbmF0aW9uc19ieV9kb25vcgAAAAAAAwAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAAAAAAZvZmZzZXQAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAA5Eb25hdGlvblJlY29yZAAA",
+        "AAAAAQAAAAAAAAAAAAAACUxvYW5UZXJtcwAAAAAAAAMAAAAAAAAADGluc3RhbGxtZW50cwAAAAQAAAAAAAAADGludGVyZXN0X2JwcwAAAAQAAAAAAAAACGludGVydmFsAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAACkxvYW5TdGF0dXMAAAAAAAgAAAAAAAAACWRlZmF1bHRlZAAAAAAAAAEAAAAAAAAAA2R1ZQAAAAALAAAAAAAAAAxpbnN0YWxsbWVudHMAAAAEAAAAAAAAAAxpbnRlcmVzdF9icHMAAAAEAAAAAAAAAAhpbnRlcnZhbAAAAAYAAAAAAAAABnJlcGFpZAAAAAAACwAAAAAAAAAFc3RhcnQAAAAAAAPoAAAABgAAAAAAAAAKdG90YWxfb3dlZAAAAAAACw==",
+        "AAAABQAAAAAAAAAAAAAACkxvYW5SZXBhaWQAAAAAAAEAAAALbG9hbl9yZXBhaWQAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAEFJlcGF5bWVudENsYWltZWQAAAABAAAAEXJlcGF5bWVudF9jbGFpbWVkAAAAAAAAAgAAAAAAAAAGbGVuZGVyAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAAAAAAAFcmVwYXkAAAAAAAACAAAAAAAAAAVvd25lcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAANY2hlY2tfZGVmYXVsdAAAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAPY2xhaW1fcmVwYXltZW50AAAAAAEAAAAAAAAABmxlbmRlcgAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAPZ2V0X2xvYW5fc3RhdHVzAAAAAAAAAAABAAAH0AAAAApMb2FuU3RhdHVzAAA=",
+        "AAAAAAAAAAAAAAAXZ2V0X2NsYWltYWJsZV9yZXBheW1lbnQAAAAAAQAAAAAAAAAGbGVuZGVyAAAAAAATAAAAAQAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAAB1ByZXNhbGUAAAAAAwAAAAAAAAAIZXNjcm93ZWQAAAALAAAAAAAAAARyYXRlAAAACwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAABQAAAAAAAAAAAAAADVRva2Vuc0NsYWltZWQAAAAAAAABAAAADnRva2Vuc19jbGFpbWVkAAAAAAADAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAAAAAAZyZWZ1bmQAAAAAAAsAAAAAAAAAAg==",
+        "AAAAAAAAAAAAAAALZ2V0X3ByZXNhbGUAAAAAAAAAAAEAAAfQAAAAB1ByZXNhbGUA",
+        "AAAAAAAAAAAAAAAMY2xhaW1fdG9rZW5zAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAMZ2V0X3NhbGVfY2FwAAAAAAAAAAEAAAPoAAAACw==",
+        "AAAAAAAAAAAAAAAOZ2V0X2FsbG9jYXRpb24AAAAAAAEAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAEAAAPtAAAAAgAAAAsAAAAL",
+        "AAAAAAAAAAAAAAAUZ2V0X2NsYWltYWJsZV90b2tlbnMAAAABAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAWcmVjbGFpbV9wcmVzYWxlX3Rva2VucwAAAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAs=",
+        "AAAABQAAAAAAAAAAAAAAClJlZnVuZFBhaWQAAAAAAAEAAAALcmVmdW5kX3BhaWQAAAAAAwAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAAAAAALZGVzdGluYXRpb24AAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAAAQAAAAAAAAAAAAAADFJlZnVuZFBvbGljeQAAAAIAAAAAAAAADGNsYWltX3BlcmlvZAAAAAYAAAAAAAAACGZhbGxiYWNrAAAAEw==",
+        "AAAABQAAAAAAAAAAAAAAE1VuY2xhaW1lZEZ1bmRzU3dlcHQAAAAAAQAAABV1bmNsYWltZWRfZnVuZHNfc3dlcHQAAAAAAAACAAAAAAAAAAhmYWxsYmFjawAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAAAAAAAAAAAAALaXNfcmVmdW5kZWQAAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAPcHJvY2Vzc19yZWZ1bmRzAAAAAAEAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAAE",
+        "AAAAAAAAAAAAAAAPc3dlZXBfdW5jbGFpbWVkAAAAAAAAAAABAAAACw==",
+        "AAAAAAAAAAAAAAARZ2V0X3JlZnVuZF9jdXJzb3IAAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAAAAAAAAAAARZ2V0X3JlZnVuZF9wb2xpY3kAAAAAAAAAAAAAAQAAA+gAAAfQAAAADFJlZnVuZFBvbGljeQ==",
+        "AAAAAAAAAAAAAAAZZ2V0X3JlZnVuZF9jbGFpbV9kZWFkbGluZQAAAAAAAAAAAAABAAAD6AAAAAY=",
+        "AAAABQAAAAAAAAAAAAAADlJldmVudWVDbGFpbWVkAAAAAAABAAAAD3JldmVudWVfY2xhaW1lZAAAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAABAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAEFJldmVudWVEZXBvc2l0ZWQAAAABAAAAEXJldmVudWVfZGVwb3NpdGVkAAAAAAAAAgAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
+        "AAAAAAAAAAAAAAANY2xhaW1fcmV2ZW51ZQAAAAAAAAEAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAAAAAAAPZGVwb3NpdF9yZXZlbnVlAAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAAAAAAAQZ2V0X3JldmVudWVfaW5mbwAAAAAAAAABAAAD7QAAAAIAAAABAAAACw==",
+        "AAAAAAAAAAAAAAAVZ2V0X2NsYWltYWJsZV9yZXZlbnVlAAAAAAAAAQAAAAAAAAAFZG9ub3IAAAAAAAATAAAAAQAAAAs=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAEgAAAAEAAAAAAAAACERvbmF0aW9uAAAAAQAAABMAAAABAAAAAAAAAAVEb25vcgAAAAAAAAEAAAAEAAAAAQAAAAAAAAAGUGxlZGdlAAAAAAABAAAAEwAAAAEAAAAAAAAAB1BsZWRnZXIAAAAAAQAAAAQAAAABAAAAAAAAAA5Eb25hdGlvblJlY29yZAAAAAAAAQAAAAQAAAABAAAAAAAAAAlBbm9ueW1vdXMAAAAAAAABAAAAEwAAAAEAAAAAAAAAEkRvbm9yRG9uYXRpb25Db3VudAAAAAAAAQAAABMAAAABAAAAAAAAAA1Eb25vckRvbmF0aW9uAAAAAAAAAgAAABMAAAAEAAAAAQAAAAAAAAAIUmVmdW5kVG8AAAABAAAAEwAAAAEAAAAAAAAADE1hdGNoaW5nUG9vbAAAAAEAAAAEAAAAAQAAAAAAAAAIUmVmdW5kZWQAAAABAAAAEwAAAAEAAAAAAAAADExhc3REb25hdGlvbgAAAAEAAAATAAAAAQAAAAAAAAAHRGlzcHV0ZQAAAAABAAAABAAAAAEAAAAAAAAAEVJldmVudWVDaGVja3BvaW50AAAAAAAAAQAAABMAAAABAAAAAAAAAA5Mb2FuQ2hlY2twb2ludAAAAAAAAQAAABMAAAABAAAAAAAAAA1Ub2tlbnNDbGFpbWVkAAAAAAAAAQAAABMAAAABAAAAAAAAAAhQcm9wb3NhbAAAAAEAAAAEAAAAAQAAAAAAAAAHTWF0Y2hlZAAAAAACAAAAEwAAAAQ=",
+        "AAAABQAAAAAAAAAAAAAAD1N0b3JhZ2VNaWdyYXRlZAAAAAABAAAAEHN0b3JhZ2VfbWlncmF0ZWQAAAACAAAAAAAAAAxmcm9tX3ZlcnNpb24AAAAEAAAAAAAAAAAAAAAKdG9fdmVyc2lvbgAAAAAABAAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAEENvbnRyYWN0VXBncmFkZWQAAAABAAAAEWNvbnRyYWN0X3VwZ3JhZGVkAAAAAAAAAQAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAAAAAAI=",
+        "AAAAAAAAAAAAAAAHbWlncmF0ZQAAAAAAAAAAAA==",
+        "AAAAAAAAAAAAAAAHdXBncmFkZQAAAAACAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAAAAAAAASZ2V0X3NjaGVtYV92ZXJzaW9uAAAAAAAAAAAAAQAAAAQ=",
+        "AAAAAQAAAAAAAAAAAAAADVZlc3RpbmdTdGF0dXMAAAAAAAAHAAAAAAAAAAljbGFpbWFibGUAAAAAAAALAAAAAAAAAAdjbGFpbWVkAAAAAAsAAAAAAAAABWNsaWZmAAAAAAAABgAAAAAAAAAIZHVyYXRpb24AAAAGAAAAAAAAAAVzdGFydAAAAAAAA+gAAAAGAAAAAAAAAAV0b3RhbAAAAAAAAAsAAAAAAAAABnZlc3RlZAAAAAAACw==",
+        "AAAAAQAAAAAAAAAAAAAAD1Zlc3RpbmdTY2hlZHVsZQAAAAACAAAAAAAAAAVjbGlmZgAAAAAAAAYAAAAAAAAACGR1cmF0aW9uAAAABg==",
+        "AAAAAAAAAAAAAAAMY2xhaW1fdmVzdGVkAAAAAQAAAAAAAAAFb3duZXIAAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAASZ2V0X3Zlc3Rpbmdfc3RhdHVzAAAAAAAAAAAAAQAAB9AAAAANVmVzdGluZ1N0YXR1cwAAAA==",
+        "AAAAAQAAAAAAAAAAAAAADE1hdGNoaW5nUG9vbAAAAAYAAAAAAAAAA2NhcAAAAAALAAAAAAAAAAlkZXBvc2l0ZWQAAAAAAAALAAAAAAAAAAdtYXRjaGVkAAAAAAsAAAAAAAAACXJhdGlvX2JwcwAAAAAAAAQAAAAAAAAACHJldHVybmVkAAAAAQAAAAAAAAAHc3BvbnNvcgAAAAAT",
+        "AAAAAAAAAAAAAAANcmVjZWl2ZV9tYXRjaAAAAAAAAAIAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAAAAAAAAPcmV0dXJuX21hdGNoaW5nAAAAAAEAAAAAAAAAB3Bvb2xfaWQAAAAABAAAAAEAAAAL",
+        "AAAAAAAAAAAAAAARYWRkX21hdGNoaW5nX3Bvb2wAAAAAAAAEAAAAAAAAAAdzcG9uc29yAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAJcmF0aW9fYnBzAAAAAAAABAAAAAAAAAADY2FwAAAAAAsAAAABAAAABA==",
+        "AAAAAAAAAAAAAAARZ2V0X21hdGNoaW5nX3Bvb2wAAAAAAAABAAAAAAAAAAdwb29sX2lkAAAAAAQAAAABAAAH0AAAAAxNYXRjaGluZ1Bvb2w=",
+        "AAAAAAAAAAAAAAARZ2V0X3RvdGFsX21hdGNoZWQAAAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAAAAAAAXZ2V0X21hdGNoaW5nX3Bvb2xfY291bnQAAAAAAAAAAAEAAAAE",
+        "AAAAAQAAAAAAAAAAAAAACFByb3Bvc2FsAAAABQAAAAAAAAAGYWN0aW9uAAAAAAfQAAAADlByb3Bvc2FsQWN0aW9uAAAAAAAAAAAACWFwcHJvdmFscwAAAAAAA+oAAAATAAAAAAAAAAhleGVjdXRlZAAAAAEAAAAAAAAACmV4cGlyZXNfYXQAAAAAAAYAAAAAAAAACHByb3Bvc2VyAAAAEw==",
+        "AAAAAgAAAAAAAAAAAAAADlByb3Bvc2FsQWN0aW9uAAAAAAAFAAAAAQAAAAAAAAAIV2l0aGRyYXcAAAABAAAAEwAAAAEAAAAAAAAAClNldFNpZ25lcnMAAAAAAAIAAAPqAAAAEwAAAAQAAAABAAAAAAAAABFUcmFuc2Zlck93bmVyc2hpcAAAAAAAAAEAAAATAAAAAAAAAAAAAAATQ2FuY2VsT3duZXJUcmFuc2ZlcgAAAAABAAAAAAAAAAtEcmF3V2lubmVycwAAAAACAAAABAAAAAE=",
+        "AAAAAAAAAAAAAAALZ2V0X3NpZ25lcnMAAAAAAAAAAAEAAAPqAAAAEw==",
+        "AAAAAAAAAAAAAAALc2V0X3NpZ25lcnMAAAAAAwAAAAAAAAAFb3duZXIAAAAAAAATAAAAAAAAAAdzaWduZXJzAAAAA+oAAAATAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAAAAAAAMZ2V0X3Byb3Bvc2FsAAAAAQAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAEAAAfQAAAACFByb3Bvc2Fs",
+        "AAAAAAAAAAAAAAANZ2V0X3RocmVzaG9sZAAAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAOYXBwcm92ZV9hY3Rpb24AAAAAAAIAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAAOZXhlY3V0ZV9hY3Rpb24AAAAAAAIAAAAAAAAABnNpZ25lcgAAAAAAEwAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAA=",
+        "AAAAAAAAAAAAAAAOcHJvcG9zZV9hY3Rpb24AAAAAAAIAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAADlByb3Bvc2FsQWN0aW9uAAAAAAABAAAABA==",
+        "AAAAAAAAAAAAAAAOZ2V0X3N0YXJ0X3RpbWUAAAAAAAAAAAABAAAABg==",
+        "AAAABQAAAAAAAAAAAAAAEFdpdGhkcmF3YWxWZXRvZWQAAAABAAAAEXdpdGhkcmF3YWxfdmV0b2VkAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAQAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAAAAAAI=",
+        "AAAAAQAAAAAAAAAAAAAAEVBlbmRpbmdXaXRoZHJhd2FsAAAAAAAAAgAAAAAAAAAKcmVsZWFzZV9hdAAAAAAABgAAAAAAAAAMcmVxdWVzdGVkX2F0AAAABg==",
+        "AAAABQAAAAAAAAAAAAAAEldpdGhkcmF3YWxFeGVjdXRlZAAAAAAAAQAAABN3aXRoZHJhd2FsX2V4ZWN1dGVkAAAAAAEAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAC",
+        "AAAABQAAAAAAAAAAAAAAE1dpdGhkcmF3YWxSZXF1ZXN0ZWQAAAAAAQAAABR3aXRoZHJhd2FsX3JlcXVlc3RlZAAAAAIAAAAAAAAABW93bmVyAAAAAAAAEwAAAAEAAAAAAAAACnJlbGVhc2VfYXQAAAAAAAYAAAAAAAAAAg==",
+        "AAAAAAAAAAAAAAAPdmV0b193aXRoZHJhd2FsAAAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAGcmVhc29uAAAAAAAQAAAAAA==",
+        "AAAAAAAAAAAAAAASZXhlY3V0ZV93aXRoZHJhd2FsAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAScmVxdWVzdF93aXRoZHJhd2FsAAAAAAABAAAAAAAAAAVvd25lcgAAAAAAABMAAAABAAAABg==",
+        "AAAAAAAAAAAAAAAUZ2V0X3dpdGhkcmF3YWxfZGVsYXkAAAAAAAAAAQAAAAY=",
+        "AAAAAAAAAAAAAAAUaXNfd2l0aGRyYXdhbF92ZXRvZWQAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAAAAAAAUbGlmdF93aXRoZHJhd2FsX3ZldG8AAAABAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAAWZ2V0X3BlbmRpbmdfd2l0aGRyYXdhbAAAAAAAAAAAAAEAAAPoAAAH0AAAABFQZW5kaW5nV2l0aGRyYXdhbAAAAA==",
+        "AAAABQAAAAAAAAAAAAAAEURvbmF0aW9uV2l0aGRyYXduAAAAAAAAAQAAABJkb25hdGlvbl93aXRoZHJhd24AAAAAAAIAAAAAAAAABWRvbm9yAAAAAAAAEwAAAAEAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAAAAAAAAAAAAAIdW5wbGVkZ2UAAAACAAAAAAAAAAVkb25vcgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAAAAAAAMZ2V0X2Nvb2xfb2ZmAAAAAAAAAAEAAAAG",
+        "AAAABQAAAAAAAAAAAAAAFE93bmVyc2hpcFRyYW5zZmVycmVkAAAAAQAAABVvd25lcnNoaXBfdHJhbnNmZXJyZWQAAAAAAAACAAAAAAAAAA5wcmV2aW91c19vd25lcgAAAAAAEwAAAAEAAAAAAAAACW5ld19vd25lcgAAAAAAABMAAAABAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAGU93bmVyc2hpcFRyYW5zZmVyUHJvcG9zZWQAAAAAAAABAAAAG293bmVyc2hpcF90cmFuc2Zlcl9wcm9wb3NlZAAAAAACAAAAAAAAAA1jdXJyZW50X293bmVyAAAAAAAAEwAAAAEAAAAAAAAADXBlbmRpbmdfb3duZXIAAAAAAAATAAAAAQAAAAI=",
+        "AAAABQAAAAAAAAAAAAAAGk93bmVyc2hpcFRyYW5zZmVyQ2FuY2VsbGVkAAAAAAABAAAAHG93bmVyc2hpcF90cmFuc2Zlcl9jYW5jZWxsZWQAAAACAAAAAAAAAA1jdXJyZW50X293bmVyAAAAAAAAEwAAAAEAAAAAAAAADXBlbmRpbmdfb3duZXIAAAAAAAATAAAAAQAAAAI=",
+        "AAAAAAAAAAAAAAAQYWNjZXB0X293bmVyc2hpcAAAAAEAAAAAAAAACW5ld19vd25lcgAAAAAAABMAAAAA",
+        "AAAAAAAAAAAAAAARZ2V0X3BlbmRpbmdfb3duZXIAAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAAAAAAAVY2FuY2VsX293bmVyX3RyYW5zZmVyAAAAAAAAAQAAAAAAAAAHY3VycmVudAAAAAATAAAAAA==",
+        "AAAAAAAAAAAAAAAWcHJvcG9zZV9vd25lcl90cmFuc2ZlcgAAAAAAAgAAAAAAAAAHY3VycmVudAAAAAATAAAAAAAAAAluZXdfb3duZXIAAAAAAAATAAAAAA==" ]),
       options
     )
   }
   public readonly fromJSON = {
-    donate: this.txFromJSON<null>,
+    get_ruling: this.txFromJSON<Option<Ruling>>,
+        get_arbiter: this.txFromJSON<Option<ArbiterConfig>>,
+        get_dispute: this.txFromJSON<Dispute>,
+        open_dispute: this.txFromJSON<u32>,
+        expire_dispute: this.txFromJSON<i128>,
+        resolve_dispute: this.txFromJSON<null>,
+        get_dispute_count: this.txFromJSON<u32>,
+        get_dispute_refund: this.txFromJSON<i128>,
+        claim_dispute_refund: this.txFromJSON<i128>,
+        get_arbitration_state: this.txFromJSON<u32>,
+        donate: this.txFromJSON<null>,
         refund: this.txFromJSON<null>,
         withdraw: this.txFromJSON<null>,
+        donate_for: this.txFromJSON<null>,
         initialize: this.txFromJSON<null>,
         get_donation: this.txFromJSON<i128>,
-        get_all_donors: this.txFromJSON<Array<readonly [string, i128]>>,
+        get_all_donors: this.txFromJSON<Array<readonly [Option<string>, i128]>>,
+        donate_with_note: this.txFromJSON<null>,
         get_min_donation: this.txFromJSON<i128>,
         get_total_raised: this.txFromJSON<i128>,
         get_campaign_info: this.txFromJSON<readonly [string, i128, u64, string, string, string, i128, u32]>,
         is_deadline_passed: this.txFromJSON<boolean>,
         get_campaign_status: this.txFromJSON<u32>,
         get_is_already_init: this.txFromJSON<boolean>,
-        get_progress_percentage: this.txFromJSON<u32>
+        get_refund_destination: this.txFromJSON<string>,
+        set_refund_destination: this.txFromJSON<null>,
+        get_progress_percentage: this.txFromJSON<u32>,
+        is_paused: this.txFromJSON<boolean>,
+        get_platform: this.txFromJSON<string>,
+        set_campaign_paused: this.txFromJSON<null>,
+        pledge: this.txFromJSON<null>,
+        collect: this.txFromJSON<u32>,
+        get_pledge: this.txFromJSON<Pledge>,
+        get_total_pledged: this.txFromJSON<i128>,
+        get_total_defaulted: this.txFromJSON<i128>,
+        draw_winners: this.txFromJSON<Array<string>>,
+        get_raffle_winners: this.txFromJSON<Array<string>>,
+        get_donation_count: this.txFromJSON<u32>,
+        get_donation_record: this.txFromJSON<PublicDonation>,
+        get_donation_history: this.txFromJSON<Array<PublicDonation>>,
+        get_recent_donations: this.txFromJSON<Array<PublicDonation>>,
+        get_donations_by_donor: this.txFromJSON<Array<DonationRecord>>,
+        repay: this.txFromJSON<null>,
+        check_default: this.txFromJSON<u32>,
+        claim_repayment: this.txFromJSON<i128>,
+        get_loan_status: this.txFromJSON<LoanStatus>,
+        get_claimable_repayment: this.txFromJSON<i128>,
+        get_presale: this.txFromJSON<Presale>,
+        claim_tokens: this.txFromJSON<i128>,
+        get_sale_cap: this.txFromJSON<Option<i128>>,
+        get_allocation: this.txFromJSON<readonly [i128, i128]>,
+        get_claimable_tokens: this.txFromJSON<i128>,
+        reclaim_presale_tokens: this.txFromJSON<i128>,
+        is_refunded: this.txFromJSON<boolean>,
+        process_refunds: this.txFromJSON<u32>,
+        sweep_unclaimed: this.txFromJSON<i128>,
+        get_refund_cursor: this.txFromJSON<u32>,
+        get_refund_policy: this.txFromJSON<Option<RefundPolicy>>,
+        get_refund_claim_deadline: this.txFromJSON<Option<u64>>,
+        claim_revenue: this.txFromJSON<i128>,
+        deposit_revenue: this.txFromJSON<null>,
+        get_revenue_info: this.txFromJSON<readonly [boolean, i128]>,
+        get_claimable_revenue: this.txFromJSON<i128>,
+        migrate: this.txFromJSON<null>,
+        upgrade: this.txFromJSON<null>,
+        get_schema_version: this.txFromJSON<u32>,
+        claim_vested: this.txFromJSON<i128>,
+        get_vesting_status: this.txFromJSON<VestingStatus>,
+        receive_match: this.txFromJSON<null>,
+        return_matching: this.txFromJSON<i128>,
+        add_matching_pool: this.txFromJSON<u32>,
+        get_matching_pool: this.txFromJSON<MatchingPool>,
+        get_total_matched: this.txFromJSON<i128>,
+        get_matching_pool_count: this.txFromJSON<u32>,
+        get_signers: this.txFromJSON<Array<string>>,
+        set_signers: this.txFromJSON<null>,
+        get_proposal: this.txFromJSON<Proposal>,
+        get_threshold: this.txFromJSON<u32>,
+        approve_action: this.txFromJSON<null>,
+        execute_action: this.txFromJSON<null>,
+        propose_action: this.txFromJSON<u32>,
+        get_start_time: this.txFromJSON<u64>,
+        veto_withdrawal: this.txFromJSON<null>,
+        execute_withdrawal: this.txFromJSON<null>,
+        request_withdrawal: this.txFromJSON<u64>,
+        get_withdrawal_delay: this.txFromJSON<u64>,
+        is_withdrawal_vetoed: this.txFromJSON<boolean>,
+        lift_withdrawal_veto: this.txFromJSON<null>,
+        get_pending_withdrawal: this.txFromJSON<Option<PendingWithdrawal>>,
+        unpledge: this.txFromJSON<null>,
+        get_cool_off: this.txFromJSON<u64>,
+        accept_ownership: this.txFromJSON<null>,
+        get_pending_owner: this.txFromJSON<Option<string>>,
+        cancel_owner_transfer: this.txFromJSON<null>,
+        propose_owner_transfer: this.txFromJSON<null>
   }
 }
\ No newline at end of file
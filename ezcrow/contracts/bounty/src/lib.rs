@@ -0,0 +1,375 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contractevent, contractimpl, contracttype, symbol_short, token, Address, Env, String,
+    Symbol, Vec,
+};
+
+// Storage keys for our contract data
+const BOUNTY_OWNER: Symbol = symbol_short!("owner");
+const BOUNTY_TITLE: Symbol = symbol_short!("title");
+const BOUNTY_DESC: Symbol = symbol_short!("desc");
+const TOKEN_ADDRESS: Symbol = symbol_short!("token");
+const BOUNTY_DEADLINE: Symbol = symbol_short!("deadline");
+const REVIEWERS: Symbol = symbol_short!("reviewers");
+const TOTAL_POT: Symbol = symbol_short!("pot");
+const BOUNTY_STATUS: Symbol = symbol_short!("status");
+const CONTRIBUTOR_COUNT: Symbol = symbol_short!("contr_cnt");
+const CLAIM_COUNT: Symbol = symbol_short!("claim_cnt");
+const IS_ALREADY_INIT: Symbol = symbol_short!("is_init");
+
+// Proof is a URI (repository PR, commit, IPFS...), so it stays short
+pub const MAX_PROOF_LEN: u32 = 256;
+
+// Bounty status enum
+#[derive(Clone, Copy, PartialEq)]
+pub enum BountyStatus {
+    Open = 0,    // Taking contributions and claims
+    Paid = 1,    // A claim was approved and the pot paid out
+    Expired = 2, // Deadline passed with no approved claim; contributors can get refunds
+}
+
+// Keys for per-entry data kept in persistent storage
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Contribution(Address), // Contributor's total added to the pot
+    Contributor(u32),      // Contributor index -> address, in order of first contribution
+    Claim(u32),            // Claim, by sequential ID
+}
+
+// A claim that the task was done
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Claim {
+    pub id: u32,
+    pub claimant: Address,
+    pub proof_uri: String,
+    pub submitted_at: u64,
+}
+
+// Emitted when someone adds to the pot
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyFunded {
+    #[topic]
+    pub contributor: Address,
+    pub amount: i128,
+}
+
+// Emitted when a claim is submitted
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimSubmitted {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub claimant: Address,
+    pub proof_uri: String,
+}
+
+// Emitted when a claim is approved and the pot paid out
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyPaid {
+    #[topic]
+    pub claim_id: u32,
+    #[topic]
+    pub claimant: Address,
+    pub approver: Address,
+    pub amount: i128,
+}
+
+// Emitted when a contributor takes their share back from an expired bounty
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyRefunded {
+    #[topic]
+    pub contributor: Address,
+    pub amount: i128,
+}
+
+// A crowdfunded bounty: anyone adds to the pot, and the owner or a reviewer
+// pays it to the claimant whose work they approve
+#[contract]
+pub struct BountyContract;
+
+#[contractimpl]
+impl BountyContract {
+    // Set up the bounty
+    pub fn initialize(
+        env: Env,
+        owner: Address,          // Who posted the task
+        title: String,           // Task title
+        description: String,     // What has to be done
+        token: Address,          // Token the pot is paid in
+        deadline: u64,           // Unix timestamp by which a claim must be approved
+        reviewers: Vec<Address>, // Who besides the owner can approve claims
+    ) {
+        // Verify the owner is who they claim to be
+        owner.require_auth();
+
+        if env.storage().instance().has(&IS_ALREADY_INIT) {
+            panic!("Bounty already initialized");
+        }
+        if deadline <= env.ledger().timestamp() {
+            panic!("Deadline must be in the future");
+        }
+
+        env.storage().instance().set(&BOUNTY_OWNER, &owner);
+        env.storage().instance().set(&BOUNTY_TITLE, &title);
+        env.storage().instance().set(&BOUNTY_DESC, &description);
+        env.storage().instance().set(&TOKEN_ADDRESS, &token);
+        env.storage().instance().set(&BOUNTY_DEADLINE, &deadline);
+        env.storage().instance().set(&REVIEWERS, &reviewers);
+        env.storage().instance().set(&TOTAL_POT, &0i128);
+        env.storage()
+            .instance()
+            .set(&BOUNTY_STATUS, &(BountyStatus::Open as u32));
+        env.storage().instance().set(&IS_ALREADY_INIT, &true);
+    }
+
+    // Add to the pot
+    pub fn fund(env: Env, contributor: Address, amount: i128) {
+        // Verify the contributor is authorized
+        contributor.require_auth();
+        ensure_open(&env);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        // Transfer tokens from the contributor to this contract
+        let contract_address = env.current_contract_address();
+        bounty_token(&env).transfer(&contributor, &contract_address, &amount);
+
+        // Update the pot
+        let pot: i128 = env.storage().instance().get(&TOTAL_POT).unwrap();
+        env.storage().instance().set(&TOTAL_POT, &(pot + amount));
+
+        // Track the contributor's share, adding them to the index on first contribution
+        let key = DataKey::Contribution(contributor.clone());
+        let current: i128 = match env.storage().persistent().get(&key) {
+            Some(current) => current,
+            None => {
+                let count = contributor_count(&env);
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Contributor(count), &contributor);
+                env.storage()
+                    .instance()
+                    .set(&CONTRIBUTOR_COUNT, &(count + 1));
+                0
+            }
+        };
+        env.storage().persistent().set(&key, &(current + amount));
+
+        BountyFunded {
+            contributor,
+            amount,
+        }
+        .publish(&env);
+    }
+
+    // Claim the bounty by pointing at the finished work. Returns the claim ID
+    pub fn submit_claim(env: Env, claimant: Address, proof_uri: String) -> u32 {
+        // Verify the claimant is authorized
+        claimant.require_auth();
+        ensure_open(&env);
+
+        if proof_uri.is_empty() || proof_uri.len() > MAX_PROOF_LEN {
+            panic!("Proof URI must be 1 to 256 bytes");
+        }
+
+        // Whoever approves claims can't pay the pot to themselves
+        let owner: Address = env.storage().instance().get(&BOUNTY_OWNER).unwrap();
+        let reviewers: Vec<Address> = env.storage().instance().get(&REVIEWERS).unwrap();
+        if claimant == owner || reviewers.contains(&claimant) {
+            panic!("The owner and reviewers can't claim the bounty");
+        }
+
+        let id: u32 = env.storage().instance().get(&CLAIM_COUNT).unwrap_or(0);
+        env.storage().persistent().set(
+            &DataKey::Claim(id),
+            &Claim {
+                id,
+                claimant: claimant.clone(),
+                proof_uri: proof_uri.clone(),
+                submitted_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().instance().set(&CLAIM_COUNT, &(id + 1));
+
+        ClaimSubmitted {
+            id,
+            claimant,
+            proof_uri,
+        }
+        .publish(&env);
+
+        id
+    }
+
+    // Approve a claim and pay the whole pot to its claimant
+    pub fn approve_claim(env: Env, approver: Address, claim_id: u32) {
+        // Verify the approver is authorized
+        approver.require_auth();
+
+        let owner: Address = env.storage().instance().get(&BOUNTY_OWNER).unwrap();
+        let reviewers: Vec<Address> = env.storage().instance().get(&REVIEWERS).unwrap();
+        if approver != owner && !reviewers.contains(&approver) {
+            panic!("Only the owner or a reviewer can approve claims");
+        }
+        ensure_open(&env);
+
+        let claim = Self::get_claim(env.clone(), claim_id);
+        if claim.claimant == approver {
+            panic!("Claimants can't approve their own claim");
+        }
+        let pot: i128 = env.storage().instance().get(&TOTAL_POT).unwrap();
+        if pot <= 0 {
+            panic!("Bounty has no funds");
+        }
+
+        env.storage()
+            .instance()
+            .set(&BOUNTY_STATUS, &(BountyStatus::Paid as u32));
+        env.storage().instance().set(&TOTAL_POT, &0i128);
+
+        bounty_token(&env).transfer(&env.current_contract_address(), &claim.claimant, &pot);
+
+        BountyPaid {
+            claim_id,
+            claimant: claim.claimant,
+            approver,
+            amount: pot,
+        }
+        .publish(&env);
+    }
+
+    // Take back a contribution once the deadline passed with no approved claim
+    pub fn refund(env: Env, contributor: Address) {
+        // Verify the contributor is authorized
+        contributor.require_auth();
+
+        if refresh_status(&env) != BountyStatus::Expired as u32 {
+            panic!("Refunds only available for expired bounties");
+        }
+
+        let key = DataKey::Contribution(contributor.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount <= 0 {
+            panic!("No contribution found for this address");
+        }
+
+        // Clear the contribution and update the pot
+        env.storage().persistent().set(&key, &0i128);
+        let pot: i128 = env.storage().instance().get(&TOTAL_POT).unwrap();
+        env.storage().instance().set(&TOTAL_POT, &(pot - amount));
+
+        bounty_token(&env).transfer(&env.current_contract_address(), &contributor, &amount);
+
+        BountyRefunded {
+            contributor,
+            amount,
+        }
+        .publish(&env);
+    }
+
+    // Get bounty information (owner, title, description, deadline, pot, status)
+    pub fn get_bounty_info(env: Env) -> (Address, String, String, u64, i128, u32) {
+        if !env.storage().instance().has(&IS_ALREADY_INIT) {
+            panic!("Bounty not initialized");
+        }
+
+        (
+            env.storage().instance().get(&BOUNTY_OWNER).unwrap(),
+            env.storage().instance().get(&BOUNTY_TITLE).unwrap(),
+            env.storage().instance().get(&BOUNTY_DESC).unwrap(),
+            env.storage().instance().get(&BOUNTY_DEADLINE).unwrap(),
+            env.storage().instance().get(&TOTAL_POT).unwrap(),
+            Self::get_status(env),
+        )
+    }
+
+    // Get the BountyStatus as u32 (an open bounty past its deadline reads as expired)
+    pub fn get_status(env: Env) -> u32 {
+        let status: u32 = env.storage().instance().get(&BOUNTY_STATUS).unwrap();
+        let deadline: u64 = env.storage().instance().get(&BOUNTY_DEADLINE).unwrap();
+        if status == BountyStatus::Open as u32 && env.ledger().timestamp() > deadline {
+            return BountyStatus::Expired as u32;
+        }
+        status
+    }
+
+    // Get the amount currently in the pot
+    pub fn get_pot(env: Env) -> i128 {
+        env.storage().instance().get(&TOTAL_POT).unwrap_or(0)
+    }
+
+    // Get how much an address has added to the pot
+    pub fn get_contribution(env: Env, contributor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Contribution(contributor))
+            .unwrap_or(0)
+    }
+
+    // Get all contributors and their current contributions
+    pub fn get_all_contributors(env: Env) -> Vec<(Address, i128)> {
+        let mut contributors = Vec::new(&env);
+        for index in 0..contributor_count(&env) {
+            let address: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Contributor(index))
+                .unwrap();
+            let amount = Self::get_contribution(env.clone(), address.clone());
+            contributors.push_back((address, amount));
+        }
+        contributors
+    }
+
+    // Get a claim by ID
+    pub fn get_claim(env: Env, claim_id: u32) -> Claim {
+        match env.storage().persistent().get(&DataKey::Claim(claim_id)) {
+            Some(claim) => claim,
+            None => panic!("Claim not found"),
+        }
+    }
+
+    // Get how many claims have been submitted
+    pub fn get_claim_count(env: Env) -> u32 {
+        env.storage().instance().get(&CLAIM_COUNT).unwrap_or(0)
+    }
+}
+
+// Panic unless the bounty is still taking contributions, claims and approvals
+fn ensure_open(env: &Env) {
+    match refresh_status(env) {
+        s if s == BountyStatus::Paid as u32 => panic!("Bounty already paid"),
+        s if s == BountyStatus::Expired as u32 => panic!("Bounty has expired"),
+        _ => {}
+    }
+}
+
+// Settle an open bounty as expired once its deadline has passed
+fn refresh_status(env: &Env) -> u32 {
+    let status = BountyContract::get_status(env.clone());
+    env.storage().instance().set(&BOUNTY_STATUS, &status);
+    status
+}
+
+fn bounty_token(env: &Env) -> token::Client<'_> {
+    let token_address: Address = env.storage().instance().get(&TOKEN_ADDRESS).unwrap();
+    token::Client::new(env, &token_address)
+}
+
+fn contributor_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&CONTRIBUTOR_COUNT)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,169 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::Address as _, testutils::Events, testutils::Ledger, token, Address, Env, IntoVal,
+};
+
+// Helper: a bounty due in a day with one reviewer, backed by a real token
+fn setup_bounty<'a>(
+    env: &Env,
+) -> (
+    BountyContractClient<'a>,
+    Address,
+    Address,
+    token::StellarAssetClient<'a>,
+) {
+    let contract_id = env.register(BountyContract, ());
+    let client = BountyContractClient::new(env, &contract_id);
+
+    let owner = Address::generate(env);
+    let reviewer = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    env.mock_all_auths();
+
+    client.initialize(
+        &owner,
+        &String::from_str(env, "Fix the parser"),
+        &String::from_str(env, "Handle nested quotes in CSV import"),
+        &token_address,
+        &(env.ledger().timestamp() + 86400),
+        &soroban_sdk::vec![env, reviewer.clone()],
+    );
+
+    (
+        client,
+        owner,
+        reviewer,
+        token::StellarAssetClient::new(env, &token_address),
+    )
+}
+
+// Helper: mint tokens to a fresh contributor and add them to the pot
+fn fund_from_new_contributor(
+    env: &Env,
+    client: &BountyContractClient,
+    token_admin: &token::StellarAssetClient,
+    amount: i128,
+) -> Address {
+    let contributor = Address::generate(env);
+    token_admin.mint(&contributor, &amount);
+    client.fund(&contributor, &amount);
+    contributor
+}
+
+// Test 1: A reviewer approves a claim and the whole pot goes to the claimant
+#[test]
+fn test_approve_claim_pays_pot() {
+    let env = Env::default();
+    let (client, _, reviewer, token_admin) = setup_bounty(&env);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    fund_from_new_contributor(&env, &client, &token_admin, 30_000_000);
+    fund_from_new_contributor(&env, &client, &token_admin, 20_000_000);
+    assert_eq!(client.get_pot(), 50_000_000);
+
+    let claimant = Address::generate(&env);
+    let claim_id = client.submit_claim(
+        &claimant,
+        &String::from_str(&env, "https://github.com/org/repo/pull/42"),
+    );
+
+    client.approve_claim(&reviewer, &claim_id);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "bounty_paid"),
+            claim_id,
+            claimant.clone()
+        )
+            .into_val(&env)
+    );
+
+    assert_eq!(token.balance(&claimant), 50_000_000);
+    assert_eq!(client.get_pot(), 0);
+    assert_eq!(client.get_status(), BountyStatus::Paid as u32);
+}
+
+// Test 2: Contributors get their money back when nothing was approved in time
+#[test]
+fn test_refund_after_expiry() {
+    let env = Env::default();
+    let (client, _, _, token_admin) = setup_bounty(&env);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let contributor = fund_from_new_contributor(&env, &client, &token_admin, 30_000_000);
+    client.submit_claim(
+        &Address::generate(&env),
+        &String::from_str(&env, "ipfs://unfinished"),
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+    assert_eq!(client.get_status(), BountyStatus::Expired as u32);
+
+    client.refund(&contributor);
+    assert_eq!(token.balance(&contributor), 30_000_000);
+    assert_eq!(client.get_pot(), 0);
+}
+
+// Test 3: Claims can't be approved after the deadline
+#[test]
+#[should_panic(expected = "Bounty has expired")]
+fn test_approve_after_deadline() {
+    let env = Env::default();
+    let (client, owner, _, token_admin) = setup_bounty(&env);
+
+    fund_from_new_contributor(&env, &client, &token_admin, 30_000_000);
+    let claim_id = client.submit_claim(
+        &Address::generate(&env),
+        &String::from_str(&env, "ipfs://late"),
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    client.approve_claim(&owner, &claim_id);
+}
+
+// Test 4: Only the owner or a reviewer can approve
+#[test]
+#[should_panic(expected = "Only the owner or a reviewer can approve claims")]
+fn test_approve_not_reviewer() {
+    let env = Env::default();
+    let (client, _, _, token_admin) = setup_bounty(&env);
+
+    fund_from_new_contributor(&env, &client, &token_admin, 30_000_000);
+    let claimant = Address::generate(&env);
+    let claim_id = client.submit_claim(&claimant, &String::from_str(&env, "ipfs://work"));
+
+    client.approve_claim(&claimant, &claim_id);
+}
+
+// Test 5: No refunds while the bounty is still open
+#[test]
+#[should_panic(expected = "Refunds only available for expired bounties")]
+fn test_refund_while_open() {
+    let env = Env::default();
+    let (client, _, _, token_admin) = setup_bounty(&env);
+
+    let contributor = fund_from_new_contributor(&env, &client, &token_admin, 30_000_000);
+
+    client.refund(&contributor);
+}
+
+// Test 6: Reviewers can't claim the bounty they approve
+#[test]
+#[should_panic(expected = "The owner and reviewers can't claim the bounty")]
+fn test_reviewer_claim() {
+    let env = Env::default();
+    let (client, _, reviewer, _) = setup_bounty(&env);
+
+    client.submit_claim(&reviewer, &String::from_str(&env, "ipfs://work"));
+}
@@ -0,0 +1,368 @@
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol,
+};
+
+use crate::{
+    matching, refresh_status, refunds, storage, storage::DataKey, vesting, xlm_token,
+    CampaignStatus, CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient,
+    RefundPaid, TOTAL_RAISED,
+};
+
+// Storage keys for arbitration
+const ARBITER: Symbol = symbol_short!("arbiter");
+const ARBITRATION_STATE: Symbol = symbol_short!("arb_state");
+const DISPUTE_COUNT: Symbol = symbol_short!("disp_cnt");
+const RULING: Symbol = symbol_short!("ruling");
+
+// Ruling shares are in basis points: 10_000 refunds everything
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+// Evidence is a hash or URI, so it stays short
+pub const MAX_EVIDENCE_LEN: u32 = 256;
+
+// The arbiter gets this long after the first dispute to rule. Past it, anyone
+// can settle the disputes as a full refund of the donations
+pub const DISPUTE_TIMEOUT: u64 = 30 * 24 * 60 * 60;
+
+// Arbitration state enum
+#[derive(Clone, Copy, PartialEq)]
+pub enum ArbitrationState {
+    Open = 0,     // No dispute so far
+    Disputed = 1, // Funds frozen until the arbiter rules or the dispute times out
+    Resolved = 2, // The arbiter has ruled
+}
+
+// The arbiter the owner named and how long donors can challenge after success
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbiterConfig {
+    pub arbiter: Address,
+    pub challenge_window: u64, // Seconds after the campaign succeeds
+}
+
+// A donor's challenge, kept for the record
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub id: u32,
+    pub donor: Address,
+    pub evidence: String, // Hash or URI of the donor's evidence
+    pub opened_at: u64,
+}
+
+// The arbiter's decision
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ruling {
+    pub refund_bps: u32,   // Share of the donations refunded to donors pro-rata
+    pub refund_pool: i128, // Amount set aside for donor refunds
+    pub donated: i128,     // Donations the pool is shared across
+    pub resolved_at: u64,
+}
+
+// Emitted when the owner names an arbiter
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbiterSet {
+    #[topic]
+    pub arbiter: Address,
+    pub challenge_window: u64,
+}
+
+// Emitted when a donor challenges the campaign
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeOpened {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub donor: Address,
+    pub evidence: String,
+}
+
+// Emitted when the arbiter rules on the disputes
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolved {
+    #[topic]
+    pub arbiter: Address,
+    pub refund_bps: u32,
+    pub refund_pool: i128,
+}
+
+// Emitted when the arbiter didn't rule in time and every donation was made
+// refundable
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeExpired {
+    pub refund_pool: i128,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Challenge a successful campaign with evidence. Freezes the payout until the
+    // arbiter rules. Returns the dispute ID
+    pub fn open_dispute(env: Env, donor: Address, evidence: String) -> u32 {
+        // Verify the donor is authorized
+        donor.require_auth();
+
+        let config = arbiter_config(&env);
+        if storage::donation_of(&env, &donor) <= 0 {
+            panic!("No donation found for this address");
+        }
+        if refresh_status(&env) != CampaignStatus::Successful as u32 {
+            panic!("Campaign is not successful");
+        }
+        if state(&env) == ArbitrationState::Resolved as u32 {
+            panic!("Disputes already resolved");
+        }
+        if evidence.len() > MAX_EVIDENCE_LEN {
+            panic!("Evidence is too long");
+        }
+
+        let succeeded_at = vesting::success_at(&env).unwrap();
+        if env.ledger().timestamp() > succeeded_at + config.challenge_window {
+            panic!("Challenge window has closed");
+        }
+
+        let id: u32 = env.storage().instance().get(&DISPUTE_COUNT).unwrap_or(0);
+        env.storage().persistent().set(
+            &DataKey::Dispute(id),
+            &Dispute {
+                id,
+                donor: donor.clone(),
+                evidence: evidence.clone(),
+                opened_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().instance().set(&DISPUTE_COUNT, &(id + 1));
+        env.storage()
+            .instance()
+            .set(&ARBITRATION_STATE, &(ArbitrationState::Disputed as u32));
+
+        DisputeOpened {
+            id,
+            donor,
+            evidence,
+        }
+        .publish(&env);
+
+        id
+    }
+
+    // Rule on the open disputes. `refund_bps` of the donations is set aside for
+    // donors to claim pro-rata (0 releases everything to the owner, 10_000
+    // refunds every donation); the rest, and any matching, can be withdrawn by
+    // the owner
+    pub fn resolve_dispute(env: Env, arbiter: Address, refund_bps: u32) {
+        // Verify the arbiter is authorized
+        arbiter.require_auth();
+        if arbiter != arbiter_config(&env).arbiter {
+            panic!("Only the arbiter can perform this action");
+        }
+
+        if state(&env) != ArbitrationState::Disputed as u32 {
+            panic!("No open dispute");
+        }
+        if refund_bps as i128 > BPS_DENOMINATOR {
+            panic!("Refund share cannot exceed 10000 basis points");
+        }
+
+        let refund_pool = rule(&env, refund_bps);
+
+        DisputeResolved {
+            arbiter,
+            refund_bps,
+            refund_pool,
+        }
+        .publish(&env);
+    }
+
+    // Settle disputes the arbiter left unresolved past the dispute timeout by
+    // refunding every donation. Anyone can call it. Returns the refund pool
+    pub fn expire_dispute(env: Env) -> i128 {
+        if state(&env) != ArbitrationState::Disputed as u32 {
+            panic!("No open dispute");
+        }
+        let opened_at = Self::get_dispute(env.clone(), 0).opened_at;
+        if env.ledger().timestamp() <= opened_at + DISPUTE_TIMEOUT {
+            panic!("Dispute has not timed out");
+        }
+
+        let refund_pool = rule(&env, BPS_DENOMINATOR as u32);
+
+        DisputeExpired { refund_pool }.publish(&env);
+
+        refund_pool
+    }
+
+    // Claim a donor's pro-rata share of the refunds the arbiter ordered.
+    // It goes to the donor's refund destination. Returns the amount paid
+    pub fn claim_dispute_refund(env: Env, donor: Address) -> i128 {
+        // Verify the donor is authorized
+        donor.require_auth();
+
+        if refunds::is_refunded(&env, &donor) {
+            panic!("Donation already refunded");
+        }
+        let amount = Self::get_dispute_refund(env.clone(), donor.clone());
+        if amount <= 0 {
+            panic!("No refund owed to this address");
+        }
+
+        // Only what the donor still has in the campaign keeps counting
+        env.storage()
+            .persistent()
+            .set(&DataKey::Refunded(donor.clone()), &true);
+        let donation = storage::donation_of(&env, &donor);
+        storage::set_donation(&env, &donor, donation - amount);
+
+        let destination = storage::refund_destination(&env, &donor);
+        xlm_token(&env).transfer(&env.current_contract_address(), &destination, &amount);
+
+        RefundPaid {
+            donor,
+            destination,
+            amount,
+        }
+        .publish(&env);
+
+        amount
+    }
+
+    // Get what a donor can claim under the arbiter's ruling
+    pub fn get_dispute_refund(env: Env, donor: Address) -> i128 {
+        let ruling: Ruling = match env.storage().instance().get(&RULING) {
+            Some(ruling) => ruling,
+            None => return 0,
+        };
+        if ruling.donated <= 0 || refunds::is_refunded(&env, &donor) {
+            return 0;
+        }
+        ruling.refund_pool * storage::donation_of(&env, &donor) / ruling.donated
+    }
+
+    // Get the arbiter and challenge window, if the owner named one
+    pub fn get_arbiter(env: Env) -> Option<ArbiterConfig> {
+        env.storage().instance().get(&ARBITER)
+    }
+
+    // Get the ArbitrationState as u32
+    pub fn get_arbitration_state(env: Env) -> u32 {
+        state(&env)
+    }
+
+    // Get a dispute by ID
+    pub fn get_dispute(env: Env, id: u32) -> Dispute {
+        match env.storage().persistent().get(&DataKey::Dispute(id)) {
+            Some(dispute) => dispute,
+            None => panic!("Dispute not found"),
+        }
+    }
+
+    // Get how many disputes have been opened
+    pub fn get_dispute_count(env: Env) -> u32 {
+        env.storage().instance().get(&DISPUTE_COUNT).unwrap_or(0)
+    }
+
+    // Get the arbiter's ruling, once there is one
+    pub fn get_ruling(env: Env) -> Option<Ruling> {
+        env.storage().instance().get(&RULING)
+    }
+}
+
+// Name an arbiter who settles donor disputes raised within `challenge_window`
+// seconds of success. Without an arbiter there is no challenge period
+pub(crate) fn configure(
+    env: &Env,
+    owner: &Address,
+    arbiter: &Option<Address>,
+    challenge_window: u64,
+) {
+    let arbiter = match arbiter {
+        Some(arbiter) => arbiter.clone(),
+        None => return,
+    };
+    if arbiter == *owner {
+        panic!("Arbiter must not be the campaign owner");
+    }
+    if challenge_window == 0 {
+        panic!("Challenge window must be positive");
+    }
+
+    env.storage().instance().set(
+        &ARBITER,
+        &ArbiterConfig {
+            arbiter: arbiter.clone(),
+            challenge_window,
+        },
+    );
+
+    ArbiterSet {
+        arbiter,
+        challenge_window,
+    }
+    .publish(env);
+}
+
+// Record a ruling refunding `refund_bps` of the donations and take the refund
+// pool out of the total raised. Refunds come out of the donations, so no donor
+// gets back more than they gave. Returns the refund pool
+fn rule(env: &Env, refund_bps: u32) -> i128 {
+    let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+    let donated = total - matching::total_matched(env);
+    let refund_pool = donated * refund_bps as i128 / BPS_DENOMINATOR;
+
+    env.storage().instance().set(
+        &RULING,
+        &Ruling {
+            refund_bps,
+            refund_pool,
+            donated,
+            resolved_at: env.ledger().timestamp(),
+        },
+    );
+    env.storage()
+        .instance()
+        .set(&ARBITRATION_STATE, &(ArbitrationState::Resolved as u32));
+    env.storage()
+        .instance()
+        .set(&TOTAL_RAISED, &(total - refund_pool));
+
+    refund_pool
+}
+
+// Panic while donors can still challenge the campaign or a dispute is open
+pub(crate) fn ensure_not_frozen(env: &Env) {
+    let config: Option<ArbiterConfig> = env.storage().instance().get(&ARBITER);
+    let config = match config {
+        Some(config) => config,
+        None => return,
+    };
+
+    match state(env) {
+        s if s == ArbitrationState::Disputed as u32 => panic!("Funds are frozen by a dispute"),
+        s if s == ArbitrationState::Resolved as u32 => {}
+        _ => {
+            if let Some(succeeded_at) = vesting::success_at(env) {
+                if env.ledger().timestamp() <= succeeded_at + config.challenge_window {
+                    panic!("Challenge window is still open");
+                }
+            }
+        }
+    }
+}
+
+fn arbiter_config(env: &Env) -> ArbiterConfig {
+    match env.storage().instance().get(&ARBITER) {
+        Some(config) => config,
+        None => panic!("Campaign has no arbiter"),
+    }
+}
+
+fn state(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ARBITRATION_STATE)
+        .unwrap_or(ArbitrationState::Open as u32)
+}
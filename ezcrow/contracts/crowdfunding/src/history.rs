@@ -0,0 +1,205 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::{
+    storage::DataKey, CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient,
+};
+
+// Number of donations recorded so far
+const DONATION_COUNT: Symbol = symbol_short!("don_cnt");
+
+// Length limits (in bytes) for what donors can attach to a donation
+pub const MAX_MESSAGE_LEN: u32 = 280;
+pub const MAX_DISPLAY_NAME_LEN: u32 = 32;
+
+// A single donation as stored on chain
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationRecord {
+    pub id: u32,        // Sequential, starting at 0
+    pub donor: Address, // Credited donor, who holds the refund rights
+    pub payer: Address, // Who paid (differs from the donor for gifts)
+    pub amount: i128,
+    pub timestamp: u64, // Ledger timestamp when the donation was made
+    pub ledger: u32,    // Ledger sequence number when the donation was made
+    pub message: Option<String>,
+    pub display_name: Option<String>,
+    pub anonymous: bool,
+}
+
+// A donation as shown publicly. Anonymous donations hide the donor and name
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PublicDonation {
+    pub id: u32,
+    pub donor: Option<Address>,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub ledger: u32,
+    pub message: Option<String>,
+    pub display_name: Option<String>,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Get up to `limit` donations starting at `offset`, oldest first, with
+    // anonymous donors masked
+    pub fn get_donation_history(env: Env, offset: u32, limit: u32) -> Vec<PublicDonation> {
+        let mut history = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(donation_count(&env));
+
+        for id in offset..end {
+            history.push_back(to_public(load_record(&env, id)));
+        }
+
+        history
+    }
+
+    // Get a single donation by ID, with an anonymous donor masked
+    pub fn get_donation_record(env: Env, id: u32) -> PublicDonation {
+        if id >= donation_count(&env) {
+            panic!("Donation not found");
+        }
+        to_public(load_record(&env, id))
+    }
+
+    // Get up to `limit` of a donor's own donations starting at `offset`, oldest
+    // first. Records are unmasked, so only the donor may read them
+    pub fn get_donations_by_donor(
+        env: Env,
+        donor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<DonationRecord> {
+        donor.require_auth();
+
+        let mut records = Vec::new(&env);
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DonorDonationCount(donor.clone()))
+            .unwrap_or(0);
+        let end = offset.saturating_add(limit).min(count);
+
+        for index in offset..end {
+            let id: u32 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::DonorDonation(donor.clone(), index))
+                .unwrap();
+            records.push_back(load_record(&env, id));
+        }
+
+        records
+    }
+
+    // Get the latest `limit` donations, newest first, for activity feeds
+    pub fn get_recent_donations(env: Env, limit: u32) -> Vec<PublicDonation> {
+        let mut recent = Vec::new(&env);
+        let count = donation_count(&env);
+        let start = count.saturating_sub(limit);
+
+        for id in (start..count).rev() {
+            recent.push_back(to_public(load_record(&env, id)));
+        }
+
+        recent
+    }
+
+    // Get how many donations have been made
+    pub fn get_donation_count(env: Env) -> u32 {
+        donation_count(&env)
+    }
+}
+
+// Check the optional message and display name against the length limits
+pub(crate) fn validate_note(message: &Option<String>, display_name: &Option<String>) {
+    if let Some(message) = message {
+        if message.len() > MAX_MESSAGE_LEN {
+            panic!("Message is too long");
+        }
+    }
+    if let Some(name) = display_name {
+        if name.len() > MAX_DISPLAY_NAME_LEN {
+            panic!("Display name is too long");
+        }
+    }
+}
+
+// Append a donation to the history. The donor's latest choice of anonymity
+// also decides how they appear in `get_all_donors`
+pub(crate) fn record_donation(
+    env: &Env,
+    payer: &Address,
+    donor: &Address,
+    amount: i128,
+    message: Option<String>,
+    display_name: Option<String>,
+    anonymous: bool,
+) {
+    let id = donation_count(env);
+    let record = DonationRecord {
+        id,
+        donor: donor.clone(),
+        payer: payer.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+        ledger: env.ledger().sequence(),
+        message,
+        display_name,
+        anonymous,
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::DonationRecord(id), &record);
+    env.storage().instance().set(&DONATION_COUNT, &(id + 1));
+
+    // Index the donation under its donor
+    let count_key = DataKey::DonorDonationCount(donor.clone());
+    let donor_count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&DataKey::DonorDonation(donor.clone(), donor_count), &id);
+    env.storage()
+        .persistent()
+        .set(&count_key, &(donor_count + 1));
+    env.storage()
+        .persistent()
+        .set(&DataKey::Anonymous(donor.clone()), &anonymous);
+}
+
+// Whether the donor asked to stay anonymous on their latest donation
+pub(crate) fn is_anonymous(env: &Env, donor: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Anonymous(donor.clone()))
+        .unwrap_or(false)
+}
+
+fn donation_count(env: &Env) -> u32 {
+    env.storage().instance().get(&DONATION_COUNT).unwrap_or(0)
+}
+
+fn load_record(env: &Env, id: u32) -> DonationRecord {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DonationRecord(id))
+        .unwrap()
+}
+
+fn to_public(record: DonationRecord) -> PublicDonation {
+    let (donor, display_name) = if record.anonymous {
+        (None, None)
+    } else {
+        (Some(record.donor), record.display_name)
+    };
+
+    PublicDonation {
+        id: record.id,
+        donor,
+        amount: record.amount,
+        timestamp: record.timestamp,
+        ledger: record.ledger,
+        message: record.message,
+        display_name,
+    }
+}
@@ -0,0 +1,215 @@
+use soroban_sdk::{contractevent, contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    presale, require_owner, revenue, storage, vesting, xlm_token, CampaignStatus,
+    CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient, DataKey,
+    CAMPAIGN_STATUS,
+};
+
+// Storage keys for the loan
+const LOAN_TERMS: Symbol = symbol_short!("loan");
+const LOAN_START: Symbol = symbol_short!("loan_at");
+const LOAN_OWED: Symbol = symbol_short!("loan_owed");
+const LOAN_REPAID: Symbol = symbol_short!("repaid");
+const REPAID_PER_SHARE: Symbol = symbol_short!("repaid_ps");
+
+// Interest is capped at 100% of the principal
+pub const MAX_INTEREST_BPS: u32 = 10_000;
+
+// Principal plus `interest_bps` is repaid in `installments` equal parts, one due
+// every `interval` seconds after the owner withdraws
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LoanTerms {
+    pub interest_bps: u32,
+    pub installments: u32,
+    pub interval: u64,
+}
+
+// Where repayment stands
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanStatus {
+    pub interest_bps: u32,
+    pub installments: u32,
+    pub interval: u64,
+    pub start: Option<u64>, // When the owner withdrew, if they have
+    pub total_owed: i128,   // Principal plus interest
+    pub due: i128,          // Owed by now under the schedule
+    pub repaid: i128,
+    pub defaulted: bool, // Behind schedule
+}
+
+// Emitted when the owner pays back part of the loan
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LoanRepaid {
+    #[topic]
+    pub owner: Address,
+    pub amount: i128,
+}
+
+// Emitted when a lender collects their share of repayments
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RepaymentClaimed {
+    #[topic]
+    pub lender: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Pay back part of the loan. Anything over what is still owed is refused
+    pub fn repay(env: Env, owner: Address, amount: i128) {
+        require_owner(&env, &owner);
+
+        let status = Self::get_loan_status(env.clone());
+        if status.start.is_none() {
+            panic!("Loan has not been withdrawn");
+        }
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        if amount > status.total_owed - status.repaid {
+            panic!("Amount exceeds what is owed");
+        }
+
+        let contract_address = env.current_contract_address();
+        xlm_token(&env).transfer(&owner, &contract_address, &amount);
+
+        let per_share =
+            repaid_per_share(&env) + amount * revenue::PRECISION / storage::total_donated(&env);
+        env.storage().instance().set(&REPAID_PER_SHARE, &per_share);
+        env.storage()
+            .instance()
+            .set(&LOAN_REPAID, &(status.repaid + amount));
+
+        LoanRepaid { owner, amount }.publish(&env);
+    }
+
+    // Pay the lender their share of everything repaid since their last claim.
+    // Returns the amount paid
+    pub fn claim_repayment(env: Env, lender: Address) -> i128 {
+        // Verify the lender is authorized
+        lender.require_auth();
+
+        let amount = Self::get_claimable_repayment(env.clone(), lender.clone());
+        if amount <= 0 {
+            panic!("No repayment to claim");
+        }
+
+        env.storage().persistent().set(
+            &DataKey::LoanCheckpoint(lender.clone()),
+            &repaid_per_share(&env),
+        );
+        xlm_token(&env).transfer(&env.current_contract_address(), &lender, &amount);
+
+        RepaymentClaimed { lender, amount }.publish(&env);
+
+        amount
+    }
+
+    // Get the campaign status, which is Defaulted while the owner has missed a
+    // repayment. Anyone can call it
+    pub fn check_default(env: Env) -> u32 {
+        let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
+        reported_status(&env, status)
+    }
+
+    // Get how much the lender can claim right now
+    pub fn get_claimable_repayment(env: Env, lender: Address) -> i128 {
+        let checkpoint: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::LoanCheckpoint(lender.clone()))
+            .unwrap_or(0);
+        storage::donation_of(&env, &lender) * (repaid_per_share(&env) - checkpoint)
+            / revenue::PRECISION
+    }
+
+    // Get the loan terms and how repayment is going
+    pub fn get_loan_status(env: Env) -> LoanStatus {
+        let terms: LoanTerms = match env.storage().instance().get(&LOAN_TERMS) {
+            Some(terms) => terms,
+            None => panic!("Campaign is not a loan"),
+        };
+        let start: Option<u64> = env.storage().instance().get(&LOAN_START);
+        let total_owed: i128 = env.storage().instance().get(&LOAN_OWED).unwrap_or(0);
+        let repaid: i128 = env.storage().instance().get(&LOAN_REPAID).unwrap_or(0);
+
+        // One installment falls due at the end of each interval
+        let due = match start {
+            Some(start) => {
+                let elapsed = env.ledger().timestamp().saturating_sub(start);
+                let installments_due = (elapsed / terms.interval).min(terms.installments as u64);
+                total_owed * installments_due as i128 / terms.installments as i128
+            }
+            None => 0,
+        };
+
+        LoanStatus {
+            interest_bps: terms.interest_bps,
+            installments: terms.installments,
+            interval: terms.interval,
+            start,
+            total_owed,
+            due,
+            repaid,
+            defaulted: repaid < due,
+        }
+    }
+}
+
+// Turn donations into loans the owner repays with interest. All-zero terms
+// leave the campaign a plain donation
+pub(crate) fn configure(env: &Env, terms: &LoanTerms) {
+    if *terms == LoanTerms::default() {
+        return;
+    }
+    if vesting::is_enabled(env) {
+        panic!("Loans can't be combined with vesting");
+    }
+    if terms.interest_bps > MAX_INTEREST_BPS || terms.installments == 0 || terms.interval == 0 {
+        panic!("Invalid loan terms");
+    }
+    env.storage().instance().set(&LOAN_TERMS, terms);
+}
+
+// Whether donations are loans to be repaid
+pub(crate) fn is_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&LOAN_TERMS)
+}
+
+// Start the repayment schedule once the owner has the money
+pub(crate) fn record_withdrawal(env: &Env) {
+    if !is_enabled(env) {
+        return;
+    }
+    let terms: LoanTerms = env.storage().instance().get(&LOAN_TERMS).unwrap();
+    let principal = storage::total_donated(env) - presale::oversubscribed(env);
+    let owed = principal + principal * terms.interest_bps as i128 / 10_000;
+
+    env.storage()
+        .instance()
+        .set(&LOAN_START, &env.ledger().timestamp());
+    env.storage().instance().set(&LOAN_OWED, &owed);
+}
+
+// The status views report: a withdrawn loan is Defaulted while it is behind
+// schedule. The stored status stays Withdrawn, so revenue, matching and token
+// claims keep working for a defaulted loan
+pub(crate) fn reported_status(env: &Env, status: u32) -> u32 {
+    if is_enabled(env)
+        && status == CampaignStatus::Withdrawn as u32
+        && CrowdfundingContract::get_loan_status(env.clone()).defaulted
+    {
+        CampaignStatus::Defaulted as u32
+    } else {
+        status
+    }
+}
+
+fn repaid_per_share(env: &Env) -> i128 {
+    env.storage().instance().get(&REPAID_PER_SHARE).unwrap_or(0)
+}
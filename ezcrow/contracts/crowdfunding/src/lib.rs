@@ -1,13 +1,14 @@
 #![no_std]
+// Contract entry points mirror the frontend form, so some take many arguments
+#![allow(clippy::too_many_arguments)]
 use soroban_sdk::{
-    contract, contractimpl, symbol_short, token, Address, Env, Map, String, Symbol, Vec,
+    contract, contractimpl, contracttype, symbol_short, token, Address, Env, String, Symbol, Vec,
 };
 
 // Storage keys for our contract data
 const CAMPAIGN_GOAL: Symbol = symbol_short!("goal");
 const CAMPAIGN_DEADLINE: Symbol = symbol_short!("deadline");
 const TOTAL_RAISED: Symbol = symbol_short!("raised");
-const DONATIONS: Symbol = symbol_short!("donations");
 const CAMPAIGN_OWNER: Symbol = symbol_short!("owner");
 const XLM_TOKEN_ADDRESS: Symbol = symbol_short!("xlm_addr");
 const IS_ALREADY_INIT: Symbol = symbol_short!("is_init");
@@ -18,6 +19,46 @@ const MIN_DONATION: Symbol = symbol_short!("min_don");
 const CAMPAIGN_STATUS: Symbol = symbol_short!("status");
 const WITHDRAWN: Symbol = symbol_short!("withdrawn");
 
+mod arbitration;
+mod history;
+mod lending;
+mod matching;
+mod multisig;
+mod ownership;
+mod pause;
+mod pledge;
+mod presale;
+mod raffle;
+mod refunds;
+mod revenue;
+mod schedule;
+mod storage;
+mod timelock;
+mod unpledge;
+mod upgrade;
+mod vesting;
+
+pub use arbitration::{
+    ArbiterConfig, ArbiterSet, ArbitrationState, Dispute, DisputeExpired, DisputeOpened,
+    DisputeResolved, Ruling, DISPUTE_TIMEOUT,
+};
+pub use history::{DonationRecord, PublicDonation};
+pub use lending::{LoanRepaid, LoanStatus, LoanTerms, RepaymentClaimed};
+pub use matching::MatchingPool;
+pub use multisig::{Proposal, ProposalAction};
+pub use ownership::{OwnershipTransferCancelled, OwnershipTransferProposed, OwnershipTransferred};
+pub use pause::{CampaignPauseChanged, Operation, PlatformClient, PlatformInterface};
+pub use pledge::{Pledge, PledgeStatus};
+pub use presale::{Presale, TokensClaimed};
+pub use raffle::WinnerDrawn;
+pub use refunds::{RefundPaid, RefundPolicy, UnclaimedFundsSwept};
+pub use revenue::{RevenueClaimed, RevenueDeposited};
+pub use storage::DataKey;
+pub use timelock::{PendingWithdrawal, WithdrawalExecuted, WithdrawalRequested, WithdrawalVetoed};
+pub use unpledge::DonationWithdrawn;
+pub use upgrade::{ContractUpgraded, StorageMigrated};
+pub use vesting::{VestingSchedule, VestingStatus};
+
 // Campaign status enum
 #[derive(Clone, Copy, PartialEq)]
 pub enum CampaignStatus {
@@ -25,6 +66,30 @@ pub enum CampaignStatus {
     Successful = 1,
     Failed = 2,
     Withdrawn = 3,
+    Archived = 4,  // Failed, and unclaimed refunds were swept to the fallback
+    Defaulted = 5, // Withdrawn loan behind its repayment schedule (reported by views only)
+    Upcoming = 6,  // Active but before its start time (reported by views only)
+}
+
+// Optional features, fixed when the campaign is created so that no donation can
+// come in before they are in place
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CampaignFeatures {
+    pub vesting: VestingSchedule, // Release funds to the owner over time (zero: all at once)
+    pub arbiter: Option<Address>, // Settles donor disputes after success
+    pub challenge_window: u64,    // Seconds donors can dispute after success
+    pub withdrawal_delay: u64,    // Seconds a withdrawal waits after it is requested
+    pub revenue_sharing: bool,    // Share future revenue with backers
+    pub loan: LoanTerms,          // Repay donations with interest (zero: plain donations)
+    pub presale_token: Option<Address>, // Project token sold to backers
+    pub presale_rate: i128,       // Project token units per 10^7 units raised
+    pub presale_amount: i128,     // Project tokens escrowed from the owner
+    pub sale_cap: i128,           // Most the presale takes in (zero: uncapped)
+    pub start_time: u64,          // When donations open (zero: straight away)
+    pub refund_fallback: Option<Address>, // Gets refunds left unclaimed after a failure
+    pub refund_claim_period: u64, // Seconds donors have to claim before that
+    pub cool_off: u64,            // Seconds a donation can be taken back past the goal
 }
 
 // Main contract struct
@@ -37,18 +102,24 @@ impl CrowdfundingContract {
     // Initialize the crowdfunding campaign with enhanced metadata
     pub fn initialize(
         env: Env,
-        owner: Address,      // Campaign creator's address
-        goal: i128,          // Target amount (in stroops: 1 XLM = 10,000,000 stroops)
-        deadline: u64,       // Unix timestamp when campaign ends
-        xlm_token: Address,  // XLM token contract address
-        title: String,       // Campaign title
-        description: String, // Campaign description
-        image_url: String,   // Campaign image URL
-        min_donation: i128,  // Minimum donation amount in stroops
+        owner: Address,             // Campaign creator's address
+        goal: i128,                 // Target amount (in stroops: 1 XLM = 10,000,000 stroops)
+        deadline: u64,              // Unix timestamp when campaign ends
+        xlm_token: Address,         // XLM token contract address
+        title: String,              // Campaign title
+        description: String,        // Campaign description
+        image_url: String,          // Campaign image URL
+        min_donation: i128,         // Minimum donation amount in stroops
+        platform: Address,          // Platform contract whose admin can pause and upgrade
+        features: CampaignFeatures, // Optional features
     ) {
         // Verify the owner is who they claim to be
         owner.require_auth();
 
+        if env.storage().instance().has(&IS_ALREADY_INIT) {
+            panic!("Campaign already initialized");
+        }
+
         // Validate inputs
         if goal <= 0 {
             panic!("Goal must be positive");
@@ -74,13 +145,37 @@ impl CrowdfundingContract {
             .instance()
             .set(&CAMPAIGN_STATUS, &(CampaignStatus::Active as u32));
         env.storage().instance().set(&WITHDRAWN, &false);
+        pause::register_platform(&env, &platform);
+
+        // Switch on the optional features
+        vesting::configure(&env, &features.vesting);
+        arbitration::configure(&env, &owner, &features.arbiter, features.challenge_window);
+        timelock::configure(&env, features.withdrawal_delay);
+        revenue::configure(&env, features.revenue_sharing);
+        lending::configure(&env, &features.loan);
+        presale::configure(
+            &env,
+            &owner,
+            &features.presale_token,
+            features.presale_rate,
+            features.presale_amount,
+            features.sale_cap,
+        );
+        schedule::configure(&env, features.start_time);
+        refunds::configure(
+            &env,
+            &features.refund_fallback,
+            features.refund_claim_period,
+        );
+        unpledge::configure(&env, features.cool_off);
 
         // Set initialization flag to true
         env.storage().instance().set(&IS_ALREADY_INIT, &true);
 
-        // Initialize empty donations map
-        let donations: Map<Address, i128> = Map::new(&env);
-        env.storage().instance().set(&DONATIONS, &donations);
+        // New campaigns start on the current storage layout
+        env.storage()
+            .instance()
+            .set(&storage::SCHEMA_VERSION, &storage::CURRENT_SCHEMA);
     }
 
     // Make a donation to the campaign
@@ -88,55 +183,51 @@ impl CrowdfundingContract {
         // Verify the donor is authorized
         donor.require_auth();
 
-        // Check if campaign is still active
-        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
-        if env.ledger().timestamp() > deadline {
-            panic!("Campaign has ended");
-        }
-
-        // Check campaign status
-        let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
-        if status != CampaignStatus::Active as u32 {
-            panic!("Campaign is not active");
-        }
-
-        // Validate donation amount
-        if amount <= 0 {
-            panic!("Donation amount must be positive");
-        }
+        // Check the campaign is accepting this contribution
+        validate_contribution(&env, amount);
 
-        // Check minimum donation requirement
-        let min_donation: i128 = env.storage().instance().get(&MIN_DONATION).unwrap();
-        if amount < min_donation {
-            panic!("Donation below minimum amount");
-        }
+        accept_donation(&env, &donor, &donor, amount, None, None, false);
+    }
 
-        // Get the XLM token address from storage and contract address
-        let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
-        let xlm_token = token::Client::new(&env, &xlm_token_address);
-        let contract_address = env.current_contract_address();
+    // Make a donation with a short public message and display name, or
+    // anonymously (hidden from public donor lists)
+    pub fn donate_with_note(
+        env: Env,
+        donor: Address,
+        amount: i128,
+        message: Option<String>,      // Up to 280 bytes
+        display_name: Option<String>, // Up to 32 bytes
+        anonymous: bool,
+    ) {
+        // Verify the donor is authorized
+        donor.require_auth();
 
-        // Transfer XLM from donor to this contract
-        xlm_token.transfer(&donor, &contract_address, &amount);
+        // Check the campaign is accepting this contribution
+        validate_contribution(&env, amount);
+        history::validate_note(&message, &display_name);
+
+        accept_donation(
+            &env,
+            &donor,
+            &donor,
+            amount,
+            message,
+            display_name,
+            anonymous,
+        );
+    }
 
-        // Update total raised
-        let mut total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
-        total += amount;
-        env.storage().instance().set(&TOTAL_RAISED, &total);
+    // Donate on behalf of someone else ("in honor of" gifts). `payer` authorizes
+    // and pays, but the contribution, refund rights and perks belong to
+    // `credited_donor`, and refunds go to the credited donor's refund destination
+    pub fn donate_for(env: Env, payer: Address, credited_donor: Address, amount: i128) {
+        // Verify the payer is authorized
+        payer.require_auth();
 
-        // Check if goal is reached and update status
-        let goal: i128 = env.storage().instance().get(&CAMPAIGN_GOAL).unwrap();
-        if total >= goal {
-            env.storage()
-                .instance()
-                .set(&CAMPAIGN_STATUS, &(CampaignStatus::Successful as u32));
-        }
+        // Check the campaign is accepting this contribution
+        validate_contribution(&env, amount);
 
-        // Track individual donor's contribution
-        let mut donations: Map<Address, i128> = env.storage().instance().get(&DONATIONS).unwrap();
-        let current_donation = donations.get(donor.clone()).unwrap_or(0);
-        donations.set(donor, current_donation + amount);
-        env.storage().instance().set(&DONATIONS, &donations);
+        accept_donation(&env, &payer, &credited_donor, amount, None, None, false);
     }
 
     // Get the total amount raised so far
@@ -146,8 +237,7 @@ impl CrowdfundingContract {
 
     // Get how much a specific donor has contributed
     pub fn get_donation(env: Env, donor: Address) -> i128 {
-        let donations: Map<Address, i128> = env.storage().instance().get(&DONATIONS).unwrap();
-        donations.get(donor).unwrap_or(0)
+        storage::donation_of(&env, &donor)
     }
 
     // Get initialization status - for frontend to check if contract is initialized
@@ -168,6 +258,7 @@ impl CrowdfundingContract {
         let image: String = env.storage().instance().get(&CAMPAIGN_IMAGE).unwrap();
         let min_donation: i128 = env.storage().instance().get(&MIN_DONATION).unwrap();
         let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
+        let status = lending::reported_status(&env, schedule::reported_status(&env, status));
 
         (
             owner,
@@ -183,10 +274,12 @@ impl CrowdfundingContract {
 
     // Get campaign status
     pub fn get_campaign_status(env: Env) -> u32 {
-        env.storage()
+        let status: u32 = env
+            .storage()
             .instance()
             .get(&CAMPAIGN_STATUS)
-            .unwrap_or(CampaignStatus::Active as u32)
+            .unwrap_or(CampaignStatus::Active as u32);
+        lending::reported_status(&env, schedule::reported_status(&env, status))
     }
 
     // Withdraw funds (only for successful campaigns and by owner)
@@ -200,53 +293,12 @@ impl CrowdfundingContract {
             panic!("Only campaign owner can withdraw");
         }
 
-        // Check if already withdrawn
-        let withdrawn: bool = env.storage().instance().get(&WITHDRAWN).unwrap_or(false);
-        if withdrawn {
-            panic!("Funds already withdrawn");
+        // Campaigns owned by a signer set withdraw through an approved proposal
+        if multisig::is_enabled(&env) {
+            panic!("Withdrawal requires multisig approval");
         }
 
-        // Check campaign status - must be successful or deadline passed
-        let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
-        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
-        let current_time = env.ledger().timestamp();
-
-        if status != CampaignStatus::Successful as u32 && current_time <= deadline {
-            panic!("Campaign must be successful or deadline must have passed");
-        }
-
-        // Update status if deadline passed but not failed yet
-        if current_time > deadline && status == CampaignStatus::Active as u32 {
-            let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
-            let goal: i128 = env.storage().instance().get(&CAMPAIGN_GOAL).unwrap();
-            if total >= goal {
-                env.storage()
-                    .instance()
-                    .set(&CAMPAIGN_STATUS, &(CampaignStatus::Successful as u32));
-            } else {
-                env.storage()
-                    .instance()
-                    .set(&CAMPAIGN_STATUS, &(CampaignStatus::Failed as u32));
-                panic!("Campaign failed to reach goal");
-            }
-        }
-
-        // Get contract balance and transfer to owner
-        let total_raised: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
-        if total_raised > 0 {
-            let xlm_token_address: Address =
-                env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
-            let xlm_token = token::Client::new(&env, &xlm_token_address);
-            let contract_address = env.current_contract_address();
-
-            xlm_token.transfer(&contract_address, &owner, &total_raised);
-        }
-
-        // Mark as withdrawn
-        env.storage().instance().set(&WITHDRAWN, &true);
-        env.storage()
-            .instance()
-            .set(&CAMPAIGN_STATUS, &(CampaignStatus::Withdrawn as u32));
+        release_funds(&env, &owner);
     }
 
     // Request refund (only for failed campaigns)
@@ -254,68 +306,53 @@ impl CrowdfundingContract {
         // Verify the donor is authorized
         donor.require_auth();
 
-        // Check if campaign has failed
-        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
-        let current_time = env.ledger().timestamp();
-        let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
-
-        // Update status if deadline passed
-        if current_time > deadline && status == CampaignStatus::Active as u32 {
-            let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
-            let goal: i128 = env.storage().instance().get(&CAMPAIGN_GOAL).unwrap();
-            if total >= goal {
-                env.storage()
-                    .instance()
-                    .set(&CAMPAIGN_STATUS, &(CampaignStatus::Successful as u32));
-            } else {
-                env.storage()
-                    .instance()
-                    .set(&CAMPAIGN_STATUS, &(CampaignStatus::Failed as u32));
-            }
-        }
-
-        // Check if campaign failed
-        let updated_status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
-        if updated_status != CampaignStatus::Failed as u32 {
+        // Check if campaign failed (settling the status if the deadline passed)
+        if refresh_status(&env) != CampaignStatus::Failed as u32 {
             panic!("Refunds only available for failed campaigns");
         }
 
-        // Get donor's contribution
-        let donations: Map<Address, i128> = env.storage().instance().get(&DONATIONS).unwrap();
-        let donation_amount = donations.get(donor.clone()).unwrap_or(0);
-
-        if donation_amount <= 0 {
+        if refunds::is_refunded(&env, &donor) {
+            panic!("Donation already refunded");
+        }
+        if storage::donation_of(&env, &donor) <= 0 {
             panic!("No donation found for this address");
         }
 
-        // Transfer refund
-        let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
-        let xlm_token = token::Client::new(&env, &xlm_token_address);
-        let contract_address = env.current_contract_address();
+        refunds::pay_refund(&env, &donor);
+    }
 
-        xlm_token.transfer(&contract_address, &donor, &donation_amount);
+    // Choose where this donor's refund is sent (defaults to the donor)
+    pub fn set_refund_destination(env: Env, donor: Address, destination: Address) {
+        // Verify the donor is authorized
+        donor.require_auth();
 
-        // Remove donor from donations map and update total
-        let mut updated_donations = donations;
-        updated_donations.remove(donor);
-        env.storage().instance().set(&DONATIONS, &updated_donations);
+        if storage::donation_of(&env, &donor) <= 0 {
+            panic!("No donation found for this address");
+        }
 
-        let mut total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
-        total -= donation_amount;
-        env.storage().instance().set(&TOTAL_RAISED, &total);
+        storage::set_refund_destination(&env, &donor, &destination);
     }
 
-    // Get all donors (returns addresses and amounts)
-    pub fn get_all_donors(env: Env) -> Vec<(Address, i128)> {
-        let donations: Map<Address, i128> = env
-            .storage()
-            .instance()
-            .get(&DONATIONS)
-            .unwrap_or(Map::new(&env));
+    // Get where this donor's refund would be sent
+    pub fn get_refund_destination(env: Env, donor: Address) -> Address {
+        storage::refund_destination(&env, &donor)
+    }
+
+    // Get all donors (returns addresses and amounts; anonymous donors have no address)
+    pub fn get_all_donors(env: Env) -> Vec<(Option<Address>, i128)> {
         let mut donors = Vec::new(&env);
 
-        for (addr, amount) in donations.iter() {
-            donors.push_back((addr, amount));
+        // Refunded donors stay in the index with nothing left, so skip them
+        for index in 0..storage::donor_count(&env) {
+            let addr = storage::donor_at(&env, index);
+            let amount = storage::donation_of(&env, &addr);
+            if amount > 0 {
+                if history::is_anonymous(&env, &addr) {
+                    donors.push_back((None, amount));
+                } else {
+                    donors.push_back((Some(addr), amount));
+                }
+            }
         }
 
         donors
@@ -354,5 +391,220 @@ impl CrowdfundingContract {
     }
 }
 
+// Take `amount` from `payer` and credit it to `donor`. Callers check
+// authorization and validate the contribution first
+fn accept_donation(
+    env: &Env,
+    payer: &Address,
+    donor: &Address,
+    amount: i128,
+    message: Option<String>,
+    display_name: Option<String>,
+    anonymous: bool,
+) {
+    // Get the XLM token address from storage and contract address
+    let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
+    let xlm_token = token::Client::new(env, &xlm_token_address);
+    let contract_address = env.current_contract_address();
+
+    // Transfer XLM from the payer to this contract
+    xlm_token.transfer(payer, &contract_address, &amount);
+
+    // Update total raised, including anything sponsors match
+    let mut total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+    total += amount + matching::apply_matching(env, donor, amount);
+    env.storage().instance().set(&TOTAL_RAISED, &total);
+
+    // Check if goal is reached and update status
+    let goal: i128 = env.storage().instance().get(&CAMPAIGN_GOAL).unwrap();
+    // Capped sales stay open until the deadline and settle then
+    if total >= goal && !presale::is_capped(env) {
+        env.storage()
+            .instance()
+            .set(&CAMPAIGN_STATUS, &(CampaignStatus::Successful as u32));
+        vesting::record_success(env);
+    }
+
+    // Track individual donor's contribution
+    let current_donation = storage::donation_of(env, donor);
+    storage::set_donation(env, donor, current_donation + amount);
+
+    // Presales can't sell more project tokens than the owner escrowed
+    presale::ensure_supply(env);
+
+    // Keep the individual donation for the public history
+    history::record_donation(env, payer, donor, amount, message, display_name, anonymous);
+
+    // Refunds go to the credited donor unless they've chosen otherwise
+    storage::init_refund_destination(env, donor);
+
+    // Start the donor's cool-off
+    unpledge::record_donation_time(env, donor);
+}
+
+// Check that the campaign is accepting contributions and the amount is allowed
+fn validate_contribution(env: &Env, amount: i128) {
+    // Check donations haven't been paused by the platform
+    pause::ensure_not_paused(env, Operation::Donate);
+
+    // Check the campaign has opened
+    schedule::ensure_started(env);
+
+    // Check if campaign is still active
+    let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+    if env.ledger().timestamp() > deadline {
+        panic!("Campaign has ended");
+    }
+
+    // Check campaign status
+    let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
+    if status != CampaignStatus::Active as u32 {
+        panic!("Campaign is not active");
+    }
+
+    // Validate donation amount
+    if amount <= 0 {
+        panic!("Donation amount must be positive");
+    }
+
+    // Check minimum donation requirement
+    let min_donation: i128 = env.storage().instance().get(&MIN_DONATION).unwrap();
+    if amount < min_donation {
+        panic!("Donation below minimum amount");
+    }
+}
+
+// Check that `caller` is the single campaign owner and has authorized the call.
+// Campaigns owned by a signer set must use a multisig proposal instead.
+fn require_owner(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    let campaign_owner: Address = env.storage().instance().get(&CAMPAIGN_OWNER).unwrap();
+    if *caller != campaign_owner {
+        panic!("Only campaign owner can perform this action");
+    }
+    if multisig::is_enabled(env) {
+        panic!("Action requires multisig approval");
+    }
+}
+
+// Settle an Active campaign as Successful or Failed once its deadline has passed.
+// While outstanding pledges could still reach the goal the campaign stays Active
+// so they can be collected first.
+fn refresh_status(env: &Env) -> u32 {
+    let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
+    let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+
+    if env.ledger().timestamp() > deadline && status == CampaignStatus::Active as u32 {
+        let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+        let goal: i128 = env.storage().instance().get(&CAMPAIGN_GOAL).unwrap();
+        let pending = pledge::pending_total(env);
+        if pending > 0 && total + pending >= goal {
+            return status;
+        }
+
+        let new_status = if total >= goal {
+            CampaignStatus::Successful
+        } else {
+            CampaignStatus::Failed
+        };
+        env.storage()
+            .instance()
+            .set(&CAMPAIGN_STATUS, &(new_status as u32));
+        if new_status == CampaignStatus::Successful {
+            vesting::record_success(env);
+        }
+        if new_status == CampaignStatus::Failed {
+            // The refund claim period runs from the deadline, or from the end
+            // of pledge collection if the collected pledges fell short
+            let failed_at = if pledge::collection_started(env) {
+                env.ledger().timestamp()
+            } else {
+                deadline
+            };
+            refunds::record_failure(env, failed_at);
+        }
+        return new_status as u32;
+    }
+
+    status
+}
+
+// Client for the token the campaign raises funds in
+fn xlm_token(env: &Env) -> token::Client<'_> {
+    let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
+    token::Client::new(env, &xlm_token_address)
+}
+
+// Panic unless the funds can be paid out to the owner right now
+fn ensure_withdrawable(env: &Env) {
+    // Check withdrawals haven't been paused by the platform
+    pause::ensure_not_paused(env, Operation::Withdraw);
+
+    // Vested campaigns pay out gradually through `claim_vested`
+    if vesting::is_enabled(env) {
+        panic!("Funds are released through vesting");
+    }
+
+    // Check if already withdrawn
+    let withdrawn: bool = env.storage().instance().get(&WITHDRAWN).unwrap_or(false);
+    if withdrawn {
+        panic!("Funds already withdrawn");
+    }
+
+    // Check campaign status - must be successful or deadline passed
+    let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
+    let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+    if status != CampaignStatus::Successful as u32 && env.ledger().timestamp() <= deadline {
+        panic!("Campaign must be successful or deadline must have passed");
+    }
+
+    match refresh_status(env) {
+        s if s == CampaignStatus::Failed as u32 => panic!("Campaign failed to reach goal"),
+        s if s == CampaignStatus::Active as u32 => panic!("Pledges are still being collected"),
+        _ => {}
+    }
+
+    // Donors can still change their mind during the cool-off
+    unpledge::ensure_cool_off_over(env);
+
+    // Donors can still challenge the campaign before the arbiter
+    arbitration::ensure_not_frozen(env);
+}
+
+// Transfer everything raised to `recipient` and mark the campaign as withdrawn.
+// Callers are responsible for checking who is allowed to trigger the payout.
+fn release_funds(env: &Env, recipient: &Address) {
+    ensure_withdrawable(env);
+
+    // Time-locked campaigns only pay out an announced withdrawal
+    timelock::take_matured_request(env);
+
+    // Get contract balance and transfer to the recipient, leaving behind what an
+    // oversubscribed sale refunds
+    let total_raised: i128 = env
+        .storage()
+        .instance()
+        .get::<_, i128>(&TOTAL_RAISED)
+        .unwrap()
+        - presale::oversubscribed(env);
+    if total_raised > 0 {
+        let xlm_token_address: Address = env.storage().instance().get(&XLM_TOKEN_ADDRESS).unwrap();
+        let xlm_token = token::Client::new(env, &xlm_token_address);
+        let contract_address = env.current_contract_address();
+
+        xlm_token.transfer(&contract_address, recipient, &total_raised);
+    }
+
+    // Mark as withdrawn
+    env.storage().instance().set(&WITHDRAWN, &true);
+    env.storage()
+        .instance()
+        .set(&CAMPAIGN_STATUS, &(CampaignStatus::Withdrawn as u32));
+
+    // Loans start falling due once the owner has the money
+    lending::record_withdrawal(env);
+}
+
 #[cfg(test)]
 mod test;
@@ -0,0 +1,286 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    refresh_status, storage::DataKey, xlm_token, CampaignStatus, CrowdfundingContract,
+    CrowdfundingContractArgs, CrowdfundingContractClient, CAMPAIGN_DEADLINE, CAMPAIGN_OWNER,
+    CAMPAIGN_STATUS, TOTAL_RAISED,
+};
+
+// Storage keys for matching accounting
+const POOL_COUNT: Symbol = symbol_short!("pool_cnt");
+const TOTAL_MATCHED: Symbol = symbol_short!("matched");
+
+// Ratios are in basis points: 10_000 matches donations 1:1
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+// A sponsor's escrowed matching funds
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchingPool {
+    pub sponsor: Address,
+    pub deposited: i128, // Escrowed by the sponsor
+    pub ratio_bps: u32,  // Matched per donated amount, in basis points
+    pub cap: i128,       // Most this pool will ever match
+    pub matched: i128,   // Credited toward the total raised so far
+    pub returned: bool,  // Whether the sponsor has been paid back
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Escrow `amount` from a sponsor to match later donations at `ratio_bps`
+    // (10_000 = 1:1), up to `cap` in total. Returns the pool ID
+    pub fn add_matching_pool(
+        env: Env,
+        sponsor: Address,
+        amount: i128,
+        ratio_bps: u32,
+        cap: i128,
+    ) -> u32 {
+        // Verify the sponsor is authorized
+        sponsor.require_auth();
+
+        // Matching only makes sense while donations are still coming in
+        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+        if env.ledger().timestamp() > deadline {
+            panic!("Campaign has ended");
+        }
+        let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
+        if status != CampaignStatus::Active as u32 {
+            panic!("Campaign is not active");
+        }
+
+        if amount <= 0 || cap <= 0 {
+            panic!("Matching amount must be positive");
+        }
+        if ratio_bps == 0 {
+            panic!("Matching ratio must be positive");
+        }
+
+        xlm_token(&env).transfer(&sponsor, env.current_contract_address(), &amount);
+
+        let id = pool_count(&env);
+        env.storage().persistent().set(
+            &DataKey::MatchingPool(id),
+            &MatchingPool {
+                sponsor,
+                deposited: amount,
+                ratio_bps,
+                cap,
+                matched: 0,
+                returned: false,
+            },
+        );
+        env.storage().instance().set(&POOL_COUNT, &(id + 1));
+
+        id
+    }
+
+    // Send a pool's leftover funds back to its sponsor once the campaign is
+    // settled. Unused matching is returned after the deadline or withdrawal of
+    // a successful campaign, and everything is returned if the campaign failed.
+    // Anyone can call it. Returns the amount sent
+    pub fn return_matching(env: Env, pool_id: u32) -> i128 {
+        let key = DataKey::MatchingPool(pool_id);
+        let mut pool: MatchingPool = match env.storage().persistent().get(&key) {
+            Some(pool) => pool,
+            None => panic!("Matching pool not found"),
+        };
+        if pool.returned {
+            panic!("Matching already returned");
+        }
+
+        // Until the deadline a cool-off withdrawal can reopen a successful
+        // campaign, and later donations are still matched
+        let status = refresh_status(&env);
+        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+        if status == CampaignStatus::Active as u32
+            || (status == CampaignStatus::Successful as u32 && env.ledger().timestamp() <= deadline)
+        {
+            panic!("Campaign is still active");
+        }
+
+        let failed =
+            status == CampaignStatus::Failed as u32 || status == CampaignStatus::Archived as u32;
+        let amount = if failed {
+            // Matched funds no longer count toward a failed campaign
+            let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+            env.storage()
+                .instance()
+                .set(&TOTAL_RAISED, &(total - pool.matched));
+            env.storage()
+                .instance()
+                .set(&TOTAL_MATCHED, &(total_matched(&env) - pool.matched));
+            pool.deposited
+        } else {
+            pool.deposited - pool.matched
+        };
+
+        pool.returned = true;
+        env.storage().persistent().set(&key, &pool);
+
+        if amount > 0 {
+            xlm_token(&env).transfer(&env.current_contract_address(), &pool.sponsor, &amount);
+        }
+
+        amount
+    }
+
+    // Take matching from an outside funder, such as a quadratic funding round,
+    // once the campaign has succeeded. It is paid out with the rest of the funds,
+    // or passed straight on to the owner if those were already withdrawn
+    pub fn receive_match(env: Env, funder: Address, amount: i128) {
+        // Verify the funder is authorized
+        funder.require_auth();
+
+        if amount <= 0 {
+            panic!("Matching amount must be positive");
+        }
+        let status = refresh_status(&env);
+        if status != CampaignStatus::Successful as u32 && status != CampaignStatus::Withdrawn as u32
+        {
+            panic!("Campaign is not successful");
+        }
+
+        let contract_address = env.current_contract_address();
+        xlm_token(&env).transfer(&funder, &contract_address, &amount);
+
+        if status == CampaignStatus::Withdrawn as u32 {
+            let owner: Address = env.storage().instance().get(&CAMPAIGN_OWNER).unwrap();
+            xlm_token(&env).transfer(&contract_address, &owner, &amount);
+        } else {
+            let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+            env.storage()
+                .instance()
+                .set(&TOTAL_RAISED, &(total + amount));
+            env.storage()
+                .instance()
+                .set(&TOTAL_MATCHED, &(total_matched(&env) + amount));
+        }
+    }
+
+    // Get a matching pool by ID
+    pub fn get_matching_pool(env: Env, pool_id: u32) -> MatchingPool {
+        match env
+            .storage()
+            .persistent()
+            .get(&DataKey::MatchingPool(pool_id))
+        {
+            Some(pool) => pool,
+            None => panic!("Matching pool not found"),
+        }
+    }
+
+    // Get how many matching pools have been added
+    pub fn get_matching_pool_count(env: Env) -> u32 {
+        pool_count(&env)
+    }
+
+    // Get how much of the total raised came from matching
+    pub fn get_total_matched(env: Env) -> i128 {
+        total_matched(&env)
+    }
+}
+
+// Matching credited to the total raised whose pools haven't been returned yet.
+// That part of the balance belongs to sponsors if the campaign fails
+pub(crate) fn unreturned_matched(env: &Env) -> i128 {
+    let mut matched = 0i128;
+    for id in 0..pool_count(env) {
+        let pool: MatchingPool = env
+            .storage()
+            .persistent()
+            .get(&DataKey::MatchingPool(id))
+            .unwrap();
+        if !pool.returned {
+            matched += pool.matched;
+        }
+    }
+    matched
+}
+
+// Match a donor's donation from every pool that still has room, in the order
+// the pools were added, recording what each pool matched for the donor.
+// Returns the total matched, which the caller adds to the total raised
+pub(crate) fn apply_matching(env: &Env, donor: &Address, amount: i128) -> i128 {
+    let mut matched = 0i128;
+
+    for id in 0..pool_count(env) {
+        let key = DataKey::MatchingPool(id);
+        let mut pool: MatchingPool = env.storage().persistent().get(&key).unwrap();
+
+        if pool.returned {
+            continue;
+        }
+
+        let room = pool.cap.min(pool.deposited) - pool.matched;
+        let share = (amount * pool.ratio_bps as i128 / BPS_DENOMINATOR).min(room);
+        if share > 0 {
+            pool.matched += share;
+            matched += share;
+            env.storage().persistent().set(&key, &pool);
+            set_matched_for(env, donor, id, matched_for(env, donor, id) + share);
+        }
+    }
+
+    if matched > 0 {
+        env.storage()
+            .instance()
+            .set(&TOTAL_MATCHED, &(total_matched(env) + matched));
+    }
+
+    matched
+}
+
+// Undo the matching for `amount` withdrawn out of a donor's `donation`, so the
+// sponsors get their funds back. Each pool gives up the same fraction of what
+// it matched for the donor, all of it when the whole donation is withdrawn.
+// Returns the total unmatched
+pub(crate) fn unapply_matching(env: &Env, donor: &Address, amount: i128, donation: i128) -> i128 {
+    let mut unmatched = 0i128;
+
+    for id in 0..pool_count(env) {
+        let key = DataKey::MatchingPool(id);
+        let mut pool: MatchingPool = env.storage().persistent().get(&key).unwrap();
+        if pool.returned {
+            continue;
+        }
+
+        let matched = matched_for(env, donor, id);
+        let share = matched * amount / donation;
+        if share > 0 {
+            pool.matched -= share;
+            unmatched += share;
+            env.storage().persistent().set(&key, &pool);
+            set_matched_for(env, donor, id, matched - share);
+        }
+    }
+
+    if unmatched > 0 {
+        env.storage()
+            .instance()
+            .set(&TOTAL_MATCHED, &(total_matched(env) - unmatched));
+    }
+
+    unmatched
+}
+
+fn matched_for(env: &Env, donor: &Address, pool_id: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Matched(donor.clone(), pool_id))
+        .unwrap_or(0)
+}
+
+fn set_matched_for(env: &Env, donor: &Address, pool_id: u32, matched: i128) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Matched(donor.clone(), pool_id), &matched);
+}
+
+fn pool_count(env: &Env) -> u32 {
+    env.storage().instance().get(&POOL_COUNT).unwrap_or(0)
+}
+
+pub(crate) fn total_matched(env: &Env) -> i128 {
+    env.storage().instance().get(&TOTAL_MATCHED).unwrap_or(0)
+}
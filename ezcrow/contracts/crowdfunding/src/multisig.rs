@@ -0,0 +1,244 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::{
+    lending, ownership, presale, raffle, release_funds, revenue, storage::DataKey, timelock,
+    vesting, CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient,
+    CAMPAIGN_OWNER,
+};
+
+// Storage keys for multisig ownership
+const SIGNERS: Symbol = symbol_short!("signers");
+const THRESHOLD: Symbol = symbol_short!("threshold");
+const NEXT_PROPOSAL: Symbol = symbol_short!("next_prop");
+
+// Proposals that don't collect enough approvals within 7 days expire
+pub const PROPOSAL_TTL: u64 = 7 * 24 * 60 * 60;
+
+// Privileged actions a signer set can vote on
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    // Pay out the raised funds to the given recipient
+    Withdraw(Address),
+    // Replace the signer set and approval threshold
+    SetSigners(Vec<Address>, u32),
+    // Offer the campaign to a new single owner (dissolves the signer set on acceptance)
+    TransferOwnership(Address),
+    // Withdraw a pending ownership offer
+    CancelOwnerTransfer,
+    // Raffle prizes among backers: winner count and whether entries are per minimum donation
+    DrawWinners(u32, bool),
+}
+
+// A pending (or executed) multisig proposal
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    pub action: ProposalAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>, // Signers that approved, proposer included
+    pub expires_at: u64,         // Unix timestamp after which it can't be approved or executed
+    pub executed: bool,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Hand campaign ownership to a set of signers (one-time, by the campaign owner)
+    pub fn set_signers(env: Env, owner: Address, signers: Vec<Address>, threshold: u32) {
+        // Verify the owner is authorized
+        owner.require_auth();
+
+        let campaign_owner: Address = env.storage().instance().get(&CAMPAIGN_OWNER).unwrap();
+        if owner != campaign_owner {
+            panic!("Only campaign owner can set signers");
+        }
+
+        // Once configured, the signer set can only be changed by a proposal
+        if is_enabled(&env) {
+            panic!("Signers already configured");
+        }
+
+        // Proposals can't claim vested funds, request a time-locked withdrawal,
+        // repay a loan or deposit revenue, so those would be stuck
+        if vesting::is_enabled(&env)
+            || timelock::is_enabled(&env)
+            || lending::is_enabled(&env)
+            || revenue::is_enabled(&env)
+        {
+            panic!("Multisig can't be combined with vesting, timelocks, loans or revenue sharing");
+        }
+        // Nor can they reclaim the unsold presale tokens
+        if presale::is_enabled(&env) {
+            panic!("Multisig can't be combined with a presale");
+        }
+
+        store_signers(&env, &signers, threshold);
+    }
+
+    // Propose a privileged action, counting as the proposer's approval. Returns the proposal ID
+    pub fn propose_action(env: Env, proposer: Address, action: ProposalAction) -> u32 {
+        // Verify the proposer is an authorized signer
+        proposer.require_auth();
+        require_signer(&env, &proposer);
+
+        // Reject invalid signer sets up front rather than at execution
+        if let ProposalAction::SetSigners(signers, threshold) = &action {
+            validate_signers(signers, *threshold);
+        }
+
+        let id: u32 = env.storage().instance().get(&NEXT_PROPOSAL).unwrap_or(0);
+        let proposal = Proposal {
+            action,
+            proposer: proposer.clone(),
+            approvals: Vec::from_array(&env, [proposer]),
+            expires_at: env.ledger().timestamp() + PROPOSAL_TTL,
+            executed: false,
+        };
+
+        save_proposal(&env, id, &proposal);
+        env.storage().instance().set(&NEXT_PROPOSAL, &(id + 1));
+
+        id
+    }
+
+    // Approve a pending proposal
+    pub fn approve_action(env: Env, signer: Address, proposal_id: u32) {
+        // Verify the signer is authorized
+        signer.require_auth();
+        require_signer(&env, &signer);
+
+        let mut proposal = open_proposal(&env, proposal_id);
+
+        if proposal.approvals.contains(&signer) {
+            panic!("Signer already approved");
+        }
+
+        proposal.approvals.push_back(signer);
+        save_proposal(&env, proposal_id, &proposal);
+    }
+
+    // Execute a proposal once it has collected enough approvals
+    pub fn execute_action(env: Env, signer: Address, proposal_id: u32) {
+        // Verify the signer is authorized
+        signer.require_auth();
+        require_signer(&env, &signer);
+
+        let mut proposal = open_proposal(&env, proposal_id);
+
+        // Only approvals from current signers count (the set may have changed)
+        let signers: Vec<Address> = env.storage().instance().get(&SIGNERS).unwrap();
+        let threshold: u32 = env.storage().instance().get(&THRESHOLD).unwrap();
+        let mut approvals = 0u32;
+        for approver in proposal.approvals.iter() {
+            if signers.contains(&approver) {
+                approvals += 1;
+            }
+        }
+        if approvals < threshold {
+            panic!("Not enough approvals");
+        }
+
+        // Mark as executed before acting on it
+        proposal.executed = true;
+        save_proposal(&env, proposal_id, &proposal);
+
+        match proposal.action {
+            ProposalAction::Withdraw(recipient) => release_funds(&env, &recipient),
+            ProposalAction::SetSigners(signers, threshold) => {
+                store_signers(&env, &signers, threshold)
+            }
+            ProposalAction::TransferOwnership(new_owner) => {
+                ownership::propose_transfer(&env, &new_owner)
+            }
+            ProposalAction::CancelOwnerTransfer => ownership::cancel_transfer(&env),
+            ProposalAction::DrawWinners(count, per_minimum) => {
+                raffle::draw(&env, count, per_minimum);
+            }
+        }
+    }
+
+    // Get the current signer set (empty if the campaign has a single owner)
+    pub fn get_signers(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&SIGNERS)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // Get the number of approvals a proposal needs (0 if multisig is not configured)
+    pub fn get_threshold(env: Env) -> u32 {
+        env.storage().instance().get(&THRESHOLD).unwrap_or(0)
+    }
+
+    // Get a proposal by ID
+    pub fn get_proposal(env: Env, proposal_id: u32) -> Proposal {
+        match env
+            .storage()
+            .persistent()
+            .get(&DataKey::Proposal(proposal_id))
+        {
+            Some(proposal) => proposal,
+            None => panic!("Proposal not found"),
+        }
+    }
+}
+
+// Whether the campaign is owned by a signer set instead of a single owner
+pub(crate) fn is_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&THRESHOLD)
+}
+
+// Drop the signer set, returning control to the single campaign owner
+pub(crate) fn clear(env: &Env) {
+    env.storage().instance().remove(&SIGNERS);
+    env.storage().instance().remove(&THRESHOLD);
+}
+
+fn require_signer(env: &Env, addr: &Address) {
+    let signers: Vec<Address> = env
+        .storage()
+        .instance()
+        .get(&SIGNERS)
+        .unwrap_or(Vec::new(env));
+    if !signers.contains(addr) {
+        panic!("Only signers can perform this action");
+    }
+}
+
+fn validate_signers(signers: &Vec<Address>, threshold: u32) {
+    if signers.is_empty() {
+        panic!("Signer set cannot be empty");
+    }
+    if threshold == 0 || threshold > signers.len() {
+        panic!("Threshold must be between 1 and the number of signers");
+    }
+    for (i, signer) in signers.iter().enumerate() {
+        if signers.first_index_of(&signer) != Some(i as u32) {
+            panic!("Duplicate signer");
+        }
+    }
+}
+
+fn store_signers(env: &Env, signers: &Vec<Address>, threshold: u32) {
+    validate_signers(signers, threshold);
+    env.storage().instance().set(&SIGNERS, signers);
+    env.storage().instance().set(&THRESHOLD, &threshold);
+}
+
+fn save_proposal(env: &Env, proposal_id: u32, proposal: &Proposal) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Proposal(proposal_id), proposal);
+}
+
+// Load a proposal that can still be approved or executed
+fn open_proposal(env: &Env, proposal_id: u32) -> Proposal {
+    let proposal = CrowdfundingContract::get_proposal(env.clone(), proposal_id);
+    if proposal.executed {
+        panic!("Proposal already executed");
+    }
+    if env.ledger().timestamp() > proposal.expires_at {
+        panic!("Proposal has expired");
+    }
+    proposal
+}
@@ -0,0 +1,113 @@
+use soroban_sdk::{contractevent, contractimpl, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    multisig, require_owner, CrowdfundingContract, CrowdfundingContractArgs,
+    CrowdfundingContractClient, CAMPAIGN_OWNER,
+};
+
+// Storage key for the address that has been offered ownership
+const PENDING_OWNER: Symbol = symbol_short!("pend_own");
+
+// Emitted when the owner offers the campaign to a new address
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferProposed {
+    #[topic]
+    pub current_owner: Address,
+    #[topic]
+    pub pending_owner: Address,
+}
+
+// Emitted when a pending transfer is withdrawn before acceptance
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferCancelled {
+    #[topic]
+    pub current_owner: Address,
+    #[topic]
+    pub pending_owner: Address,
+}
+
+// Emitted when the pending owner accepts and becomes the campaign owner
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferred {
+    #[topic]
+    pub previous_owner: Address,
+    #[topic]
+    pub new_owner: Address,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Offer the campaign to a new owner. Nothing changes until they accept
+    pub fn propose_owner_transfer(env: Env, current: Address, new_owner: Address) {
+        require_owner(&env, &current);
+        propose_transfer(&env, &new_owner);
+    }
+
+    // Accept a pending ownership transfer (must be called by the pending owner)
+    pub fn accept_ownership(env: Env, new_owner: Address) {
+        // Verify the new owner is authorized
+        new_owner.require_auth();
+
+        let pending: Option<Address> = env.storage().instance().get(&PENDING_OWNER);
+        if pending != Some(new_owner.clone()) {
+            panic!("No pending ownership transfer for this address");
+        }
+
+        let previous_owner: Address = env.storage().instance().get(&CAMPAIGN_OWNER).unwrap();
+        env.storage().instance().set(&CAMPAIGN_OWNER, &new_owner);
+        env.storage().instance().remove(&PENDING_OWNER);
+
+        // The campaign now belongs to a single address, so any signer set is dissolved
+        multisig::clear(&env);
+
+        OwnershipTransferred {
+            previous_owner,
+            new_owner,
+        }
+        .publish(&env);
+    }
+
+    // Withdraw a pending ownership offer
+    pub fn cancel_owner_transfer(env: Env, current: Address) {
+        require_owner(&env, &current);
+        cancel_transfer(&env);
+    }
+
+    // Get the address that has been offered ownership, if any
+    pub fn get_pending_owner(env: Env) -> Option<Address> {
+        env.storage().instance().get(&PENDING_OWNER)
+    }
+}
+
+pub(crate) fn propose_transfer(env: &Env, new_owner: &Address) {
+    let current_owner: Address = env.storage().instance().get(&CAMPAIGN_OWNER).unwrap();
+    if *new_owner == current_owner {
+        panic!("New owner must be a different address");
+    }
+
+    env.storage().instance().set(&PENDING_OWNER, new_owner);
+
+    OwnershipTransferProposed {
+        current_owner,
+        pending_owner: new_owner.clone(),
+    }
+    .publish(env);
+}
+
+pub(crate) fn cancel_transfer(env: &Env) {
+    let pending_owner: Address = match env.storage().instance().get(&PENDING_OWNER) {
+        Some(pending) => pending,
+        None => panic!("No pending ownership transfer"),
+    };
+    env.storage().instance().remove(&PENDING_OWNER);
+
+    let current_owner: Address = env.storage().instance().get(&CAMPAIGN_OWNER).unwrap();
+    OwnershipTransferCancelled {
+        current_owner,
+        pending_owner,
+    }
+    .publish(env);
+}
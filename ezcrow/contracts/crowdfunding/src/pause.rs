@@ -0,0 +1,100 @@
+use soroban_sdk::{
+    contractclient, contractevent, contractimpl, symbol_short, Address, Env, Map, Symbol,
+};
+
+use crate::{CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient};
+
+// Storage keys for the emergency pause
+const PLATFORM: Symbol = symbol_short!("platform");
+const PAUSED: Symbol = symbol_short!("paused");
+
+// Operations that can be paused (same numbering as the platform contract).
+// Refunds are deliberately not pausable so donors can always get their money back
+#[derive(Clone, Copy, PartialEq)]
+pub enum Operation {
+    Donate = 0,
+    Withdraw = 1,
+}
+
+// The parts of the platform contract a campaign needs
+#[contractclient(name = "PlatformClient")]
+pub trait PlatformInterface {
+    fn get_admin(env: Env) -> Address;
+    fn is_paused(env: Env, operation: u32) -> bool;
+}
+
+// Emitted whenever the platform admin pauses or resumes an operation on this campaign
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignPauseChanged {
+    #[topic]
+    pub operation: u32,
+    pub paused: bool,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Pause or resume an operation on this campaign only (platform admin)
+    pub fn set_campaign_paused(env: Env, admin: Address, operation: u32, paused: bool) {
+        // Verify the admin is authorized
+        admin.require_auth();
+        if admin != platform_admin(&env) {
+            panic!("Only platform admin can perform this action");
+        }
+
+        if operation > Operation::Withdraw as u32 {
+            panic!("Unknown operation");
+        }
+
+        let mut flags = get_flags(&env);
+        flags.set(operation, paused);
+        env.storage().instance().set(&PAUSED, &flags);
+
+        CampaignPauseChanged { operation, paused }.publish(&env);
+    }
+
+    // Check whether an operation is paused, either on this campaign or platform-wide
+    pub fn is_paused(env: Env, operation: u32) -> bool {
+        if get_flags(&env).get(operation).unwrap_or(false) {
+            return true;
+        }
+
+        let platform = Self::get_platform(env.clone());
+        PlatformClient::new(&env, &platform).is_paused(&operation)
+    }
+
+    // Get the platform contract this campaign was created on
+    pub fn get_platform(env: Env) -> Address {
+        env.storage().instance().get(&PLATFORM).unwrap()
+    }
+}
+
+// Fix the platform at creation. Its admin pauses and upgrades the campaign,
+// so the owner never gets to pick it afterwards
+pub(crate) fn register_platform(env: &Env, platform: &Address) {
+    env.storage().instance().set(&PLATFORM, platform);
+}
+
+// Get the admin of the campaign's platform
+pub(crate) fn platform_admin(env: &Env) -> Address {
+    let platform = CrowdfundingContract::get_platform(env.clone());
+    PlatformClient::new(env, &platform).get_admin()
+}
+
+// Panic if the operation is paused. Every entry point that moves funds in or out
+// (other than refunds) must call this first
+pub(crate) fn ensure_not_paused(env: &Env, operation: Operation) {
+    if CrowdfundingContract::is_paused(env.clone(), operation as u32) {
+        match operation {
+            Operation::Donate => panic!("Donations are paused"),
+            Operation::Withdraw => panic!("Withdrawals are paused"),
+        }
+    }
+}
+
+fn get_flags(env: &Env) -> Map<u32, bool> {
+    env.storage()
+        .instance()
+        .get(&PAUSED)
+        .unwrap_or(Map::new(env))
+}
@@ -0,0 +1,214 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    history, pause, presale, refresh_status, storage, storage::DataKey, validate_contribution,
+    xlm_token, CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient,
+    Operation, CAMPAIGN_DEADLINE, CAMPAIGN_GOAL, TOTAL_RAISED, WITHDRAWN,
+};
+
+// Storage keys for pledge accounting
+const PLEDGER_COUNT: Symbol = symbol_short!("pldg_cnt");
+const PENDING_PLEDGED: Symbol = symbol_short!("pledged");
+const DEFAULTED_PLEDGED: Symbol = symbol_short!("defaulted");
+const COLLECT_CURSOR: Symbol = symbol_short!("coll_cur");
+
+// Pledge status enum
+#[derive(Clone, Copy, PartialEq)]
+pub enum PledgeStatus {
+    Pending = 0,   // Waiting for the deadline
+    Collected = 1, // Pulled into the contract and counted as a donation
+    Defaulted = 2, // Allowance or balance didn't cover it at collection time
+}
+
+// A donor's promise to pay, backed by a token allowance to this contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pledge {
+    pub amount: i128,
+    pub status: u32,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Pledge to donate `amount` if the campaign succeeds. The donor must first
+    // `approve` this contract on the XLM token for at least the total pledged,
+    // with an expiration past the deadline. No funds move until `collect`
+    pub fn pledge(env: Env, donor: Address, amount: i128) {
+        // Verify the donor is authorized
+        donor.require_auth();
+
+        // Check the campaign is accepting this contribution
+        validate_contribution(&env, amount);
+
+        let key = DataKey::Pledge(donor.clone());
+        let existing: Option<Pledge> = env.storage().persistent().get(&key);
+        let total_pledge = match &existing {
+            Some(pledge) => pledge.amount + amount,
+            None => amount,
+        };
+
+        // The allowance must cover everything this donor has pledged
+        let allowance = xlm_token(&env).allowance(&donor, &env.current_contract_address());
+        if allowance < total_pledge {
+            panic!("Allowance does not cover pledge");
+        }
+
+        // First pledge from this donor, so add them to the pledger index
+        if existing.is_none() {
+            let count = pledger_count(&env);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Pledger(count), &donor);
+            env.storage().instance().set(&PLEDGER_COUNT, &(count + 1));
+        }
+
+        env.storage().persistent().set(
+            &key,
+            &Pledge {
+                amount: total_pledge,
+                status: PledgeStatus::Pending as u32,
+            },
+        );
+        env.storage()
+            .instance()
+            .set(&PENDING_PLEDGED, &(pending_total(&env) + amount));
+
+        // Pledges count against the presale supply like donations
+        presale::ensure_supply(&env);
+    }
+
+    // Pull the next `limit` pending pledges into the contract after the deadline.
+    // Anyone can call it, repeatedly, until every pledge is processed. Pledges
+    // whose allowance or balance no longer covers them are marked Defaulted and
+    // don't count toward the goal. Returns how many pledges were processed
+    pub fn collect(env: Env, limit: u32) -> u32 {
+        // Check donations haven't been paused by the platform
+        pause::ensure_not_paused(&env, Operation::Donate);
+
+        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+        if env.ledger().timestamp() <= deadline {
+            panic!("Pledges can only be collected after the deadline");
+        }
+
+        let withdrawn: bool = env.storage().instance().get(&WITHDRAWN).unwrap_or(false);
+        if withdrawn {
+            panic!("Funds already withdrawn");
+        }
+
+        // If pledges can't reach the goal the campaign fails and nothing moves
+        let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+        let goal: i128 = env.storage().instance().get(&CAMPAIGN_GOAL).unwrap();
+        if total + pending_total(&env) < goal {
+            panic!("Pledges cannot reach the goal");
+        }
+
+        let token = xlm_token(&env);
+        let contract_address = env.current_contract_address();
+        let count = pledger_count(&env);
+        let mut cursor: u32 = env.storage().instance().get(&COLLECT_CURSOR).unwrap_or(0);
+        let mut processed = 0u32;
+        let mut collected = 0i128;
+        let mut defaulted = 0i128;
+
+        while cursor < count && processed < limit {
+            let donor: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Pledger(cursor))
+                .unwrap();
+            let key = DataKey::Pledge(donor.clone());
+            let mut pledge: Pledge = env.storage().persistent().get(&key).unwrap();
+
+            if pledge.status == PledgeStatus::Pending as u32 {
+                // A revoked allowance or empty balance makes the transfer fail
+                let pulled = token.try_transfer_from(
+                    &contract_address,
+                    &donor,
+                    &contract_address,
+                    &pledge.amount,
+                );
+                if let Ok(Ok(())) = pulled {
+                    pledge.status = PledgeStatus::Collected as u32;
+                    collected += pledge.amount;
+
+                    // Collected pledges count as donations (and are refundable as such)
+                    let current_donation = storage::donation_of(&env, &donor);
+                    storage::set_donation(&env, &donor, current_donation + pledge.amount);
+                    history::record_donation(
+                        &env,
+                        &donor,
+                        &donor,
+                        pledge.amount,
+                        None,
+                        None,
+                        false,
+                    );
+                    storage::init_refund_destination(&env, &donor);
+                } else {
+                    pledge.status = PledgeStatus::Defaulted as u32;
+                    defaulted += pledge.amount;
+                }
+                env.storage().persistent().set(&key, &pledge);
+            }
+
+            cursor += 1;
+            processed += 1;
+        }
+
+        env.storage().instance().set(&COLLECT_CURSOR, &cursor);
+        env.storage()
+            .instance()
+            .set(&TOTAL_RAISED, &(total + collected));
+        env.storage().instance().set(
+            &PENDING_PLEDGED,
+            &(pending_total(&env) - collected - defaulted),
+        );
+        env.storage().instance().set(
+            &DEFAULTED_PLEDGED,
+            &(Self::get_total_defaulted(env.clone()) + defaulted),
+        );
+
+        // Settle the campaign once nothing is left to collect
+        refresh_status(&env);
+
+        processed
+    }
+
+    // Get a donor's pledge (amount and PledgeStatus as u32)
+    pub fn get_pledge(env: Env, donor: Address) -> Pledge {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Pledge(donor))
+            .unwrap_or(Pledge {
+                amount: 0,
+                status: PledgeStatus::Pending as u32,
+            })
+    }
+
+    // Get the total of pledges that haven't been collected or defaulted yet
+    pub fn get_total_pledged(env: Env) -> i128 {
+        pending_total(&env)
+    }
+
+    // Get the total of pledges that couldn't be collected
+    pub fn get_total_defaulted(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DEFAULTED_PLEDGED)
+            .unwrap_or(0)
+    }
+}
+
+// Total of pledges still waiting to be collected
+pub(crate) fn pending_total(env: &Env) -> i128 {
+    env.storage().instance().get(&PENDING_PLEDGED).unwrap_or(0)
+}
+
+// Whether `collect` has processed any pledges yet
+pub(crate) fn collection_started(env: &Env) -> bool {
+    env.storage().instance().has(&COLLECT_CURSOR)
+}
+
+fn pledger_count(env: &Env) -> u32 {
+    env.storage().instance().get(&PLEDGER_COUNT).unwrap_or(0)
+}
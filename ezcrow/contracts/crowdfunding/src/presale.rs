@@ -0,0 +1,259 @@
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, symbol_short, token, Address, Env, Symbol,
+};
+
+use crate::{
+    pledge, refresh_status, require_owner, storage, unpledge, xlm_token, CampaignStatus,
+    CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient, DataKey,
+    CAMPAIGN_GOAL,
+};
+
+// Storage keys for the presale
+const PRESALE: Symbol = symbol_short!("presale");
+const PRESALE_RECLAIMED: Symbol = symbol_short!("pre_recl");
+const SALE_CAP: Symbol = symbol_short!("sale_cap");
+
+// The rate is project token units per 10^7 units raised (per XLM for 7-decimal tokens)
+pub const RATE_SCALE: i128 = 10_000_000;
+
+// The project token being sold, its price and how much the owner escrowed
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Presale {
+    pub token: Address,
+    pub rate: i128,
+    pub escrowed: i128,
+}
+
+// Emitted when a backer receives their project tokens, and any part of their
+// contribution an oversubscribed sale couldn't take
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensClaimed {
+    #[topic]
+    pub donor: Address,
+    pub amount: i128,
+    pub refund: i128,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Send the donor the project tokens their contribution bought, and refund
+    // whatever an oversubscribed sale couldn't take. Returns the token amount
+    pub fn claim_tokens(env: Env, donor: Address) -> i128 {
+        // Verify the donor is authorized
+        donor.require_auth();
+
+        let presale = get_presale(&env);
+        let status = refresh_status(&env);
+        if status != CampaignStatus::Successful as u32 && status != CampaignStatus::Withdrawn as u32
+        {
+            panic!("Campaign is not successful");
+        }
+
+        // Donors can still change their mind during the cool-off
+        unpledge::ensure_cool_off_over(&env);
+
+        let key = DataKey::TokensClaimed(donor.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("Tokens already claimed");
+        }
+        // A small share of an oversubscribed sale can round down to no tokens
+        // and still be owed its refund
+        let amount = Self::get_claimable_tokens(env.clone(), donor.clone());
+        let (_, refund) = Self::get_allocation(env.clone(), donor.clone());
+        if amount <= 0 && refund <= 0 {
+            panic!("No tokens to claim");
+        }
+
+        env.storage().persistent().set(&key, &true);
+        let contract_address = env.current_contract_address();
+        if amount > 0 {
+            token::Client::new(&env, &presale.token).transfer(&contract_address, &donor, &amount);
+        }
+        if refund > 0 {
+            let destination = storage::refund_destination(&env, &donor);
+            xlm_token(&env).transfer(&contract_address, &destination, &refund);
+        }
+
+        TokensClaimed {
+            donor,
+            amount,
+            refund,
+        }
+        .publish(&env);
+
+        amount
+    }
+
+    // Take back the escrowed project tokens nobody bought: all of them if the
+    // campaign failed, the unsold rest if it succeeded. Returns the amount
+    pub fn reclaim_presale_tokens(env: Env, owner: Address) -> i128 {
+        require_owner(&env, &owner);
+
+        let presale = get_presale(&env);
+        if env.storage().instance().has(&PRESALE_RECLAIMED) {
+            panic!("Presale tokens already reclaimed");
+        }
+        let amount = match refresh_status(&env) {
+            s if s == CampaignStatus::Failed as u32 || s == CampaignStatus::Archived as u32 => {
+                presale.escrowed
+            }
+            s if s == CampaignStatus::Active as u32 => panic!("Campaign is still active"),
+            _ => {
+                // Shares are final once nobody can take a donation back
+                unpledge::ensure_cool_off_over(&env);
+                presale.escrowed - tokens_for(&presale, sold(&env))
+            }
+        };
+
+        env.storage().instance().set(&PRESALE_RECLAIMED, &true);
+        if amount > 0 {
+            token::Client::new(&env, &presale.token).transfer(
+                &env.current_contract_address(),
+                &owner,
+                &amount,
+            );
+        }
+
+        amount
+    }
+
+    // Get the presale terms
+    pub fn get_presale(env: Env) -> Presale {
+        get_presale(&env)
+    }
+
+    // Get how many project tokens the donor's contribution has bought
+    pub fn get_claimable_tokens(env: Env, donor: Address) -> i128 {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::TokensClaimed(donor.clone()))
+        {
+            return 0;
+        }
+        let presale = get_presale(&env);
+        let donation = storage::donation_of(&env, &donor);
+        let total = storage::total_donated(&env);
+        if oversubscribed(&env) > 0 {
+            // Each backer's share of the capped supply, rounded down
+            tokens_for(&presale, sold(&env)) * donation / total
+        } else {
+            tokens_for(&presale, donation)
+        }
+    }
+
+    // Get the sale cap, if the presale has one
+    pub fn get_sale_cap(env: Env) -> Option<i128> {
+        env.storage().instance().get(&SALE_CAP)
+    }
+
+    // Get how much of the donor's contribution the sale keeps and how much is
+    // refunded. Refunds are the donor's share of the oversubscription, rounded
+    // down, so they never add up to more than the contract holds
+    pub fn get_allocation(env: Env, donor: Address) -> (i128, i128) {
+        let donation = storage::donation_of(&env, &donor);
+        let excess = oversubscribed(&env);
+        let refund = if excess > 0 {
+            donation * excess / storage::total_donated(&env)
+        } else {
+            0
+        };
+        (donation - refund, refund)
+    }
+}
+
+// Sell a project token at a fixed rate: the owner escrows `amount` of it and
+// backers claim their share on success. Without a token there is no presale.
+// A non-zero cap limits how much the sale takes in: contributions keep coming
+// in until the deadline even past it, and if the sale is oversubscribed every
+// backer gets a pro-rata allocation with the excess refunded on claim
+pub(crate) fn configure(
+    env: &Env,
+    owner: &Address,
+    project_token: &Option<Address>,
+    rate: i128,
+    amount: i128,
+    cap: i128,
+) {
+    let project_token = match project_token {
+        Some(project_token) => project_token.clone(),
+        None if cap != 0 => panic!("Campaign has no presale"),
+        None => return,
+    };
+    if rate <= 0 || amount <= 0 {
+        panic!("Invalid presale terms");
+    }
+
+    let presale = Presale {
+        token: project_token,
+        rate,
+        escrowed: amount,
+    };
+    if cap != 0 {
+        let goal: i128 = env.storage().instance().get(&CAMPAIGN_GOAL).unwrap();
+        if cap < goal {
+            panic!("Sale cap must be at least the goal");
+        }
+        if tokens_for(&presale, cap) > presale.escrowed {
+            panic!("Escrowed tokens do not cover the cap");
+        }
+        env.storage().instance().set(&SALE_CAP, &cap);
+    }
+
+    let contract_address = env.current_contract_address();
+    token::Client::new(env, &presale.token).transfer(owner, &contract_address, &amount);
+    env.storage().instance().set(&PRESALE, &presale);
+}
+
+// Whether the campaign sells a project token
+pub(crate) fn is_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&PRESALE)
+}
+
+// Whether the presale has a cap, which keeps it open until the deadline
+pub(crate) fn is_capped(env: &Env) -> bool {
+    env.storage().instance().has(&SALE_CAP)
+}
+
+// How far contributions went past the sale cap. The owner's payout leaves
+// this much behind for the refunds
+pub(crate) fn oversubscribed(env: &Env) -> i128 {
+    match env.storage().instance().get::<_, i128>(&SALE_CAP) {
+        Some(cap) => (storage::total_donated(env) - cap).max(0),
+        None => 0,
+    }
+}
+
+// Contributions the sale keeps
+fn sold(env: &Env) -> i128 {
+    storage::total_donated(env) - oversubscribed(env)
+}
+
+// Panic if donations and outstanding pledges would buy more project tokens
+// than the owner escrowed. Campaigns without a presale are never limited
+pub(crate) fn ensure_supply(env: &Env) {
+    let presale: Presale = match env.storage().instance().get(&PRESALE) {
+        Some(presale) => presale,
+        None => return,
+    };
+    let mut committed = storage::total_donated(env) + pledge::pending_total(env);
+    if let Some(cap) = env.storage().instance().get(&SALE_CAP) {
+        committed = committed.min(cap);
+    }
+    if tokens_for(&presale, committed) > presale.escrowed {
+        panic!("Not enough presale tokens left");
+    }
+}
+
+fn get_presale(env: &Env) -> Presale {
+    match env.storage().instance().get(&PRESALE) {
+        Some(presale) => presale,
+        None => panic!("Campaign has no presale"),
+    }
+}
+
+fn tokens_for(presale: &Presale, contribution: i128) -> i128 {
+    contribution * presale.rate / RATE_SCALE
+}
@@ -0,0 +1,111 @@
+use soroban_sdk::{contractevent, contractimpl, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::{
+    refresh_status, require_owner, storage, unpledge, CampaignStatus, CrowdfundingContract,
+    CrowdfundingContractArgs, CrowdfundingContractClient, MIN_DONATION,
+};
+
+// Storage key for the drawn winners
+const RAFFLE_WINNERS: Symbol = symbol_short!("raffle");
+
+// Every draw walks the donor list once per winner, so keep it small
+pub const MAX_WINNERS: u32 = 20;
+
+// Emitted for each winner, in the order they were drawn
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WinnerDrawn {
+    #[topic]
+    pub winner: Address,
+    pub place: u32,
+    pub entries: u64,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Raffle prizes among backers once the campaign has succeeded. Each donor's
+    // chance is weighted by their contribution, or with `per_minimum` they get
+    // one entry per minimum donation. Nobody wins twice, and the draw can only
+    // happen once. Returns the winners in the order drawn
+    pub fn draw_winners(env: Env, owner: Address, count: u32, per_minimum: bool) -> Vec<Address> {
+        require_owner(&env, &owner);
+
+        draw(&env, count, per_minimum)
+    }
+
+    // Get the raffle winners in the order drawn (empty until the draw)
+    pub fn get_raffle_winners(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&RAFFLE_WINNERS)
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+// Run the draw. Callers are responsible for checking who may trigger it
+pub(crate) fn draw(env: &Env, count: u32, per_minimum: bool) -> Vec<Address> {
+    if env.storage().instance().has(&RAFFLE_WINNERS) {
+        panic!("Winners already drawn");
+    }
+    let status = refresh_status(env);
+    if status != CampaignStatus::Successful as u32 && status != CampaignStatus::Withdrawn as u32 {
+        panic!("Campaign is not successful");
+    }
+    if count == 0 || count > MAX_WINNERS {
+        panic!("Winner count must be between 1 and 20");
+    }
+
+    // Entries are final once nobody can take a donation back
+    unpledge::ensure_cool_off_over(env);
+
+    let min_donation: i128 = env.storage().instance().get(&MIN_DONATION).unwrap();
+    let mut entrants: Vec<(Address, u64)> = Vec::new(env);
+    let mut total_entries: u64 = 0;
+    for index in 0..storage::donor_count(env) {
+        let donor = storage::donor_at(env, index);
+        let donation = storage::donation_of(env, &donor);
+        let entries = if per_minimum && min_donation > 0 {
+            donation / min_donation
+        } else {
+            donation
+        } as u64;
+        if entries > 0 {
+            entrants.push_back((donor, entries));
+            total_entries += entries;
+        }
+    }
+    if entrants.len() < count {
+        panic!("Not enough eligible donors");
+    }
+
+    let mut winners = Vec::new(env);
+    for place in 1..=count {
+        // Pick a ticket and find whose range of entries it falls in
+        let mut ticket: u64 = env.prng().gen_range(0..total_entries);
+        let mut index = 0;
+        let (winner, entries) = loop {
+            let (donor, entries) = entrants.get(index).unwrap();
+            if ticket < entries {
+                break (donor, entries);
+            }
+            ticket -= entries;
+            index += 1;
+        };
+
+        // Winners leave the draw
+        entrants.remove(index);
+        total_entries -= entries;
+        winners.push_back(winner.clone());
+
+        WinnerDrawn {
+            winner,
+            place,
+            entries,
+        }
+        .publish(env);
+    }
+
+    env.storage().instance().set(&RAFFLE_WINNERS, &winners);
+
+    winners
+}
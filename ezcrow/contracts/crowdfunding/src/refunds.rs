@@ -0,0 +1,196 @@
+use soroban_sdk::{contractevent, contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    matching, refresh_status, storage, storage::DataKey, xlm_token, CampaignStatus,
+    CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient, CAMPAIGN_STATUS,
+    TOTAL_RAISED,
+};
+
+// Storage keys for refund bookkeeping
+const REFUND_CURSOR: Symbol = symbol_short!("ref_cur");
+const REFUND_POLICY: Symbol = symbol_short!("ref_pol");
+const FAILED_AT: Symbol = symbol_short!("failed_at");
+
+// Donors always get at least this long to claim before funds can be swept
+pub const MIN_CLAIM_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+// How long donors have to claim refunds after a failure, and where whatever
+// is left unclaimed goes afterwards
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPolicy {
+    pub claim_period: u64, // Seconds after the failure is settled
+    pub fallback: Address, // E.g. a charity or the platform treasury
+}
+
+// Emitted for every refund paid, whether claimed or pushed by the crank
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPaid {
+    #[topic]
+    pub donor: Address,
+    pub destination: Address,
+    pub amount: i128,
+}
+
+// Emitted when unclaimed refunds are swept and the campaign is archived
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnclaimedFundsSwept {
+    #[topic]
+    pub fallback: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Push refunds to the next `limit` donors of a failed campaign. Anyone can
+    // call it, repeatedly, until every donor is refunded. Donors who already
+    // claimed their refund are skipped. Returns how many donors were refunded
+    pub fn process_refunds(env: Env, limit: u32) -> u32 {
+        if refresh_status(&env) != CampaignStatus::Failed as u32 {
+            panic!("Refunds only available for failed campaigns");
+        }
+
+        let count = storage::donor_count(&env);
+        let mut cursor: u32 = env.storage().instance().get(&REFUND_CURSOR).unwrap_or(0);
+        let mut examined = 0u32;
+        let mut refunded = 0u32;
+
+        while cursor < count && examined < limit {
+            let donor = storage::donor_at(&env, cursor);
+            if !is_refunded(&env, &donor) && storage::donation_of(&env, &donor) > 0 {
+                pay_refund(&env, &donor);
+                refunded += 1;
+            }
+
+            cursor += 1;
+            examined += 1;
+        }
+
+        env.storage().instance().set(&REFUND_CURSOR, &cursor);
+
+        refunded
+    }
+
+    // Send every refund still unclaimed after the claim period to the fallback
+    // address and archive the campaign. Anyone can call it. Returns the amount swept
+    pub fn sweep_unclaimed(env: Env) -> i128 {
+        if refresh_status(&env) != CampaignStatus::Failed as u32 {
+            panic!("Refunds only available for failed campaigns");
+        }
+
+        let policy = match Self::get_refund_policy(env.clone()) {
+            Some(policy) => policy,
+            None => panic!("No refund policy set"),
+        };
+        let failed_at: u64 = env.storage().instance().get(&FAILED_AT).unwrap();
+        if env.ledger().timestamp() <= failed_at + policy.claim_period {
+            panic!("Refund claim period has not ended");
+        }
+
+        // Matching that hasn't been returned still belongs to the sponsors
+        let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+        let sponsored = matching::unreturned_matched(&env);
+        let amount = total - sponsored;
+
+        env.storage().instance().set(&TOTAL_RAISED, &sponsored);
+        env.storage()
+            .instance()
+            .set(&CAMPAIGN_STATUS, &(CampaignStatus::Archived as u32));
+
+        if amount > 0 {
+            xlm_token(&env).transfer(&env.current_contract_address(), &policy.fallback, &amount);
+        }
+
+        UnclaimedFundsSwept {
+            fallback: policy.fallback,
+            amount,
+        }
+        .publish(&env);
+
+        amount
+    }
+
+    // Get the refund claim period and fallback address, if the campaign has them
+    pub fn get_refund_policy(env: Env) -> Option<RefundPolicy> {
+        env.storage().instance().get(&REFUND_POLICY)
+    }
+
+    // Get when the refund claim period ends, once the campaign has failed
+    pub fn get_refund_claim_deadline(env: Env) -> Option<u64> {
+        let failed_at: u64 = env.storage().instance().get(&FAILED_AT)?;
+        let policy = Self::get_refund_policy(env)?;
+        Some(failed_at + policy.claim_period)
+    }
+
+    // Check whether a donor has been refunded
+    pub fn is_refunded(env: Env, donor: Address) -> bool {
+        is_refunded(&env, &donor)
+    }
+
+    // Get how many donors `process_refunds` has gone through so far
+    pub fn get_refund_cursor(env: Env) -> u32 {
+        env.storage().instance().get(&REFUND_CURSOR).unwrap_or(0)
+    }
+}
+
+// Set how long donors can claim refunds if the campaign fails and where
+// unclaimed funds go afterwards. Without a fallback they can claim forever
+pub(crate) fn configure(env: &Env, fallback: &Option<Address>, claim_period: u64) {
+    let fallback = match fallback {
+        Some(fallback) => fallback.clone(),
+        None => return,
+    };
+    if claim_period < MIN_CLAIM_PERIOD {
+        panic!("Claim period is too short");
+    }
+
+    env.storage().instance().set(
+        &REFUND_POLICY,
+        &RefundPolicy {
+            claim_period,
+            fallback,
+        },
+    );
+}
+
+// Send a donor's whole contribution to their refund destination and mark them
+// refunded. Callers check the campaign failed and the donor has something to refund
+pub(crate) fn pay_refund(env: &Env, donor: &Address) -> i128 {
+    let amount = storage::donation_of(env, donor);
+    let destination = storage::refund_destination(env, donor);
+
+    // Mark the donor first so the refund can never be paid twice
+    env.storage()
+        .persistent()
+        .set(&DataKey::Refunded(donor.clone()), &true);
+    storage::set_donation(env, donor, 0);
+
+    let mut total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+    total -= amount;
+    env.storage().instance().set(&TOTAL_RAISED, &total);
+
+    xlm_token(env).transfer(&env.current_contract_address(), &destination, &amount);
+
+    RefundPaid {
+        donor: donor.clone(),
+        destination,
+        amount,
+    }
+    .publish(env);
+
+    amount
+}
+
+// Remember when the campaign failed
+pub(crate) fn record_failure(env: &Env, failed_at: u64) {
+    env.storage().instance().set(&FAILED_AT, &failed_at);
+}
+
+pub(crate) fn is_refunded(env: &Env, donor: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Refunded(donor.clone()))
+        .unwrap_or(false)
+}
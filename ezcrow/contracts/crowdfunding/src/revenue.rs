@@ -0,0 +1,137 @@
+use soroban_sdk::{contractevent, contractimpl, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    refresh_status, require_owner, storage, unpledge, xlm_token, CampaignStatus,
+    CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient, DataKey,
+};
+
+// Storage keys for revenue sharing
+const REVENUE_SHARING: Symbol = symbol_short!("rev_share");
+const REVENUE_PER_SHARE: Symbol = symbol_short!("rev_ps");
+const REVENUE_DEPOSITED: Symbol = symbol_short!("rev_dep");
+
+// Revenue per share is kept scaled up so small deposits over large campaigns
+// don't round down to nothing
+pub(crate) const PRECISION: i128 = 1_000_000_000_000;
+
+// Emitted when the owner shares revenue with backers
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevenueDeposited {
+    #[topic]
+    pub owner: Address,
+    pub amount: i128,
+}
+
+// Emitted when a backer claims their share of revenue
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevenueClaimed {
+    #[topic]
+    pub donor: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Pay revenue into the campaign for backers to claim. Only once the campaign
+    // has succeeded and no donation can be taken back, so the shares are final
+    pub fn deposit_revenue(env: Env, owner: Address, amount: i128) {
+        require_owner(&env, &owner);
+
+        if !is_enabled(&env) {
+            panic!("Campaign does not share revenue");
+        }
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        let status = refresh_status(&env);
+        if status != CampaignStatus::Successful as u32 && status != CampaignStatus::Withdrawn as u32
+        {
+            panic!("Campaign is not successful");
+        }
+        unpledge::ensure_cool_off_over(&env);
+
+        let contract_address = env.current_contract_address();
+        xlm_token(&env).transfer(&owner, &contract_address, &amount);
+
+        // Anything below one unit per share stays in the contract as dust
+        let per_share = revenue_per_share(&env) + amount * PRECISION / storage::total_donated(&env);
+        env.storage().instance().set(&REVENUE_PER_SHARE, &per_share);
+        let deposited: i128 = env
+            .storage()
+            .instance()
+            .get(&REVENUE_DEPOSITED)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&REVENUE_DEPOSITED, &(deposited + amount));
+
+        RevenueDeposited { owner, amount }.publish(&env);
+    }
+
+    // Pay the donor their share of all revenue deposited since their last claim.
+    // Returns the amount paid
+    pub fn claim_revenue(env: Env, donor: Address) -> i128 {
+        // Verify the donor is authorized
+        donor.require_auth();
+
+        let amount = Self::get_claimable_revenue(env.clone(), donor.clone());
+        if amount <= 0 {
+            panic!("No revenue to claim");
+        }
+
+        env.storage().persistent().set(
+            &DataKey::RevenueCheckpoint(donor.clone()),
+            &revenue_per_share(&env),
+        );
+        xlm_token(&env).transfer(&env.current_contract_address(), &donor, &amount);
+
+        RevenueClaimed { donor, amount }.publish(&env);
+
+        amount
+    }
+
+    // Get how much revenue the donor can claim right now
+    pub fn get_claimable_revenue(env: Env, donor: Address) -> i128 {
+        let checkpoint: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RevenueCheckpoint(donor.clone()))
+            .unwrap_or(0);
+        storage::donation_of(&env, &donor) * (revenue_per_share(&env) - checkpoint) / PRECISION
+    }
+
+    // Get whether the campaign shares revenue and how much has been deposited
+    pub fn get_revenue_info(env: Env) -> (bool, i128) {
+        (
+            is_enabled(&env),
+            env.storage()
+                .instance()
+                .get(&REVENUE_DEPOSITED)
+                .unwrap_or(0),
+        )
+    }
+}
+
+// Share future revenue with backers, pro rata to their donations
+pub(crate) fn configure(env: &Env, enabled: bool) {
+    if enabled {
+        env.storage().instance().set(&REVENUE_SHARING, &true);
+    }
+}
+
+// Whether backers share the campaign's revenue
+pub(crate) fn is_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&REVENUE_SHARING)
+        .unwrap_or(false)
+}
+
+fn revenue_per_share(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&REVENUE_PER_SHARE)
+        .unwrap_or(0)
+}
@@ -0,0 +1,50 @@
+use soroban_sdk::{contractimpl, symbol_short, Env, Symbol};
+
+use crate::{
+    CampaignStatus, CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient,
+    CAMPAIGN_DEADLINE,
+};
+
+// Storage key for when donations open
+const START_TIME: Symbol = symbol_short!("start");
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Get when donations open (0 if they were open from initialization)
+    pub fn get_start_time(env: Env) -> u64 {
+        env.storage().instance().get(&START_TIME).unwrap_or(0)
+    }
+}
+
+// Announce the campaign ahead of time: donations open at `start_time` and
+// views report it as Upcoming until then. Zero opens them straight away
+pub(crate) fn configure(env: &Env, start_time: u64) {
+    if start_time == 0 {
+        return;
+    }
+    let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+    if start_time >= deadline {
+        panic!("Start time must be before the deadline");
+    }
+
+    env.storage().instance().set(&START_TIME, &start_time);
+}
+
+// Panic until the campaign's start time
+pub(crate) fn ensure_started(env: &Env) {
+    if is_upcoming(env) {
+        panic!("Campaign has not started");
+    }
+}
+
+// The status views report: an Active campaign that hasn't started is Upcoming
+pub(crate) fn reported_status(env: &Env, status: u32) -> u32 {
+    if status == CampaignStatus::Active as u32 && is_upcoming(env) {
+        return CampaignStatus::Upcoming as u32;
+    }
+    status
+}
+
+fn is_upcoming(env: &Env) -> bool {
+    env.ledger().timestamp() < CrowdfundingContract::get_start_time(env.clone())
+}
@@ -0,0 +1,119 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+// Storage layout version. Version 1 was the original contract, which kept every
+// donation in one instance `Map` but has no `upgrade` entry point, so it is never
+// migrated in place; version 2 gives each donor its own entry
+pub(crate) const SCHEMA_VERSION: Symbol = symbol_short!("schema");
+pub(crate) const CURRENT_SCHEMA: u32 = 2;
+
+// Number of addresses in the donor index
+const DONOR_COUNT: Symbol = symbol_short!("donor_cnt");
+
+// Sum of all donor contributions (sponsor matching not included)
+const TOTAL_DONATED: Symbol = symbol_short!("donated");
+
+// Keys for per-entry data kept in persistent storage
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Donation(Address),           // Donor's total contribution
+    Donor(u32),                  // Donor index -> address, in order of first donation
+    Pledge(Address),             // Pledger's pledge and its collection status
+    Pledger(u32),                // Pledger index -> address, in order of first pledge
+    DonationRecord(u32),         // Individual donation, by sequential ID
+    Anonymous(Address),          // Whether the donor hides from public lists
+    DonorDonationCount(Address), // Number of donations made by the donor
+    DonorDonation(Address, u32), // Donor's n-th donation -> donation ID
+    RefundTo(Address),           // Where the donor's refund is sent
+    MatchingPool(u32),           // Sponsor matching pool, by sequential ID
+    Refunded(Address),           // Whether the donor has been refunded
+    LastDonation(Address),       // When the donor last donated, for the cool-off
+    Dispute(u32),                // Donor dispute, by sequential ID
+    RevenueCheckpoint(Address),  // Revenue per share when the donor last claimed
+    LoanCheckpoint(Address),     // Repayments per share when the lender last claimed
+    TokensClaimed(Address),      // Whether the donor has claimed their presale tokens
+    Proposal(u32),               // Multisig proposal, by sequential ID
+    Matched(Address, u32),       // Matching a donor's contributions drew from a pool
+}
+
+// Get the schema version of the stored data
+pub(crate) fn schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&SCHEMA_VERSION)
+        .unwrap_or(CURRENT_SCHEMA)
+}
+
+// Panic if the contract was upgraded but `migrate` hasn't been run yet
+pub(crate) fn ensure_current_schema(env: &Env) {
+    if schema_version(env) < CURRENT_SCHEMA {
+        panic!("Storage migration required");
+    }
+}
+
+// Get how much a donor has contributed in total
+pub(crate) fn donation_of(env: &Env, donor: &Address) -> i128 {
+    ensure_current_schema(env);
+    env.storage()
+        .persistent()
+        .get(&DataKey::Donation(donor.clone()))
+        .unwrap_or(0)
+}
+
+// Set a donor's total contribution, adding them to the donor index on first donation
+pub(crate) fn set_donation(env: &Env, donor: &Address, amount: i128) {
+    ensure_current_schema(env);
+    let key = DataKey::Donation(donor.clone());
+    if !env.storage().persistent().has(&key) {
+        let count = donor_count(env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Donor(count), donor);
+        env.storage().instance().set(&DONOR_COUNT, &(count + 1));
+    }
+    let previous: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&TOTAL_DONATED, &(total_donated(env) + amount - previous));
+    env.storage().persistent().set(&key, &amount);
+}
+
+// Sum of every donor's current contribution
+pub(crate) fn total_donated(env: &Env) -> i128 {
+    env.storage().instance().get(&TOTAL_DONATED).unwrap_or(0)
+}
+
+// Number of addresses that have ever donated (refunded donors included)
+pub(crate) fn donor_count(env: &Env) -> u32 {
+    env.storage().instance().get(&DONOR_COUNT).unwrap_or(0)
+}
+
+// Get the donor at a position in the index
+pub(crate) fn donor_at(env: &Env, index: u32) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Donor(index))
+        .unwrap()
+}
+
+// Get where a donor's refund is sent
+pub(crate) fn refund_destination(env: &Env, donor: &Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RefundTo(donor.clone()))
+        .unwrap_or(donor.clone())
+}
+
+// Record the donor as their own refund destination on their first contribution
+pub(crate) fn init_refund_destination(env: &Env, donor: &Address) {
+    let key = DataKey::RefundTo(donor.clone());
+    if !env.storage().persistent().has(&key) {
+        env.storage().persistent().set(&key, donor);
+    }
+}
+
+pub(crate) fn set_refund_destination(env: &Env, donor: &Address, destination: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::RefundTo(donor.clone()), destination);
+}
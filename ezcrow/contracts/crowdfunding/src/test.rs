@@ -1,10 +1,28 @@
 #![cfg(test)]
+// The original tests compare booleans with assert_eq!
+#![allow(clippy::bool_assert_comparison)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, testutils::Ledger, Address, Env};
+use soroban_sdk::{
+    testutils::Address as _, testutils::Events, testutils::Ledger, token, Address, Env, IntoVal,
+};
+
+// The contract as built for deployment (`make build`), to upgrade to
+mod upgraded {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/crowdfunding.wasm");
+}
 
 const XLM_CONTRACT_TESTNET: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
 
+// Helper: deploy the platform contract a campaign is created on
+fn create_platform(env: &Env) -> Address {
+    let platform_id = env.register(platform::PlatformContract, ());
+    platform::PlatformContractClient::new(env, &platform_id)
+        .mock_all_auths()
+        .initialize(&Address::generate(env));
+    platform_id
+}
+
 // Test 1: Initialize a campaign successfully with new parameters
 #[test]
 fn test_initialize_campaign() {
@@ -21,6 +39,7 @@ fn test_initialize_campaign() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign for fundraising");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128; // 0.1 XLM minimum
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -33,6 +52,8 @@ fn test_initialize_campaign() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // Verify campaign was initialized
@@ -58,6 +79,7 @@ fn test_get_donation_no_donation() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128;
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -70,6 +92,8 @@ fn test_get_donation_no_donation() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // Check donation amount for address that never donated
@@ -94,6 +118,7 @@ fn test_donate_zero_amount() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128;
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -106,6 +131,8 @@ fn test_donate_zero_amount() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // Try to donate 0 - should panic
@@ -130,6 +157,7 @@ fn test_donate_negative_amount() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128;
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -142,6 +170,8 @@ fn test_donate_negative_amount() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // Try to donate negative amount - should panic
@@ -166,6 +196,7 @@ fn test_donate_after_deadline() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128;
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -178,6 +209,8 @@ fn test_donate_after_deadline() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // Fast forward time past deadline
@@ -216,6 +249,7 @@ fn test_is_already_init_after_initialization() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128;
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -232,6 +266,8 @@ fn test_is_already_init_after_initialization() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // After initialization, should return true
@@ -255,6 +291,7 @@ fn test_is_already_init_persists() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128;
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -268,6 +305,8 @@ fn test_is_already_init_persists() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // Verify it's initialized
@@ -297,6 +336,7 @@ fn test_get_campaign_info() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign for fundraising");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128;
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -309,6 +349,8 @@ fn test_get_campaign_info() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     let (
@@ -350,6 +392,7 @@ fn test_donate_below_minimum() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 10_000_000i128; // 1 XLM minimum
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -362,6 +405,8 @@ fn test_donate_below_minimum() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // Try to donate less than minimum - should panic
@@ -384,6 +429,7 @@ fn test_progress_percentage() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128;
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -396,6 +442,8 @@ fn test_progress_percentage() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // Initially 0%
@@ -429,6 +477,7 @@ fn test_deadline_check() {
     let description = soroban_sdk::String::from_str(&env, "A test campaign");
     let image_url = soroban_sdk::String::from_str(&env, "https://example.com/image.jpg");
     let min_donation = 1_000_000i128;
+    let platform = create_platform(&env);
 
     env.mock_all_auths();
 
@@ -441,6 +490,8 @@ fn test_deadline_check() {
         &description,
         &image_url,
         &min_donation,
+        &platform,
+        &CampaignFeatures::default(),
     );
 
     // Initially deadline not passed
@@ -454,3 +505,2295 @@ fn test_deadline_check() {
     // Now deadline should be passed
     assert_eq!(client.is_deadline_passed(), true);
 }
+
+// Helper: register a Stellar Asset Contract to stand in for XLM
+fn create_token<'a>(env: &Env) -> (Address, token::StellarAssetClient<'a>) {
+    let admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(admin);
+    let address = sac.address();
+    (
+        address.clone(),
+        token::StellarAssetClient::new(env, &address),
+    )
+}
+
+// Helper: initialize a campaign backed by a real token so donations move funds
+fn setup_campaign<'a>(
+    env: &Env,
+    goal: i128,
+) -> (
+    CrowdfundingContractClient<'a>,
+    Address,
+    token::StellarAssetClient<'a>,
+) {
+    setup_campaign_with(env, goal, CampaignFeatures::default())
+}
+
+// Helper: the same, with optional features switched on
+fn setup_campaign_with<'a>(
+    env: &Env,
+    goal: i128,
+    features: CampaignFeatures,
+) -> (
+    CrowdfundingContractClient<'a>,
+    Address,
+    token::StellarAssetClient<'a>,
+) {
+    setup_campaign_as(env, &Address::generate(env), goal, features)
+}
+
+// Helper: the same, created by the given owner
+fn setup_campaign_as<'a>(
+    env: &Env,
+    owner: &Address,
+    goal: i128,
+    features: CampaignFeatures,
+) -> (
+    CrowdfundingContractClient<'a>,
+    Address,
+    token::StellarAssetClient<'a>,
+) {
+    let contract_id = env.register(CrowdfundingContract, ());
+    let client = CrowdfundingContractClient::new(env, &contract_id);
+
+    let deadline = env.ledger().timestamp() + 86400;
+    let (token_address, token_admin) = create_token(env);
+    let title = soroban_sdk::String::from_str(env, "Test Campaign");
+    let description = soroban_sdk::String::from_str(env, "A test campaign");
+    let image_url = soroban_sdk::String::from_str(env, "https://example.com/image.jpg");
+    let min_donation = 1_000_000i128;
+    let platform = create_platform(env);
+
+    env.mock_all_auths();
+
+    client.initialize(
+        owner,
+        &goal,
+        &deadline,
+        &token_address,
+        &title,
+        &description,
+        &image_url,
+        &min_donation,
+        &platform,
+        &features,
+    );
+
+    (client, owner.clone(), token_admin)
+}
+
+// Helper: mint tokens to a fresh donor and donate them
+fn donate_from_new_donor(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token_admin: &token::StellarAssetClient,
+    amount: i128,
+) -> Address {
+    let donor = Address::generate(env);
+    token_admin.mint(&donor, &amount);
+    client.donate(&donor, &amount);
+    donor
+}
+
+// Test 13: Withdrawal through an approved multisig proposal
+#[test]
+fn test_multisig_withdraw_with_approvals() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let signer_a = Address::generate(&env);
+    let signer_b = Address::generate(&env);
+    let signer_c = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, signer_a.clone(), signer_b.clone(), signer_c.clone()];
+    client.set_signers(&owner, &signers, &2);
+
+    assert_eq!(client.get_signers(), signers);
+    assert_eq!(client.get_threshold(), 2);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    let recipient = Address::generate(&env);
+    let id = client.propose_action(&signer_a, &ProposalAction::Withdraw(recipient.clone()));
+    client.approve_action(&signer_c, &id);
+    client.execute_action(&signer_b, &id);
+
+    assert_eq!(token.balance(&recipient), 100_000_000);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Withdrawn as u32
+    );
+    assert!(client.get_proposal(&id).executed);
+}
+
+// Test 14: The single owner can no longer withdraw once signers are set
+#[test]
+#[should_panic(expected = "Withdrawal requires multisig approval")]
+fn test_multisig_blocks_direct_withdraw() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let signers = soroban_sdk::vec![&env, owner.clone(), Address::generate(&env)];
+    client.set_signers(&owner, &signers, &2);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    client.withdraw(&owner);
+}
+
+// Test 15: A proposal below the threshold cannot be executed
+#[test]
+#[should_panic(expected = "Not enough approvals")]
+fn test_multisig_execute_below_threshold() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let signer_a = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, signer_a.clone(), Address::generate(&env)];
+    client.set_signers(&owner, &signers, &2);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    let id = client.propose_action(&signer_a, &ProposalAction::Withdraw(signer_a.clone()));
+    client.execute_action(&signer_a, &id);
+}
+
+// Test 16: A signer cannot approve the same proposal twice
+#[test]
+#[should_panic(expected = "Signer already approved")]
+fn test_multisig_duplicate_approval() {
+    let env = Env::default();
+    let (client, owner, _) = setup_campaign(&env, 100_000_000);
+
+    let signer_a = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, signer_a.clone(), Address::generate(&env)];
+    client.set_signers(&owner, &signers, &2);
+
+    let id = client.propose_action(&signer_a, &ProposalAction::Withdraw(signer_a.clone()));
+    client.approve_action(&signer_a, &id);
+}
+
+// Test 17: Stale proposals expire
+#[test]
+#[should_panic(expected = "Proposal has expired")]
+fn test_multisig_proposal_expires() {
+    let env = Env::default();
+    let (client, owner, _) = setup_campaign(&env, 100_000_000);
+
+    let signer_a = Address::generate(&env);
+    let signer_b = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, signer_a.clone(), signer_b.clone()];
+    client.set_signers(&owner, &signers, &2);
+
+    let id = client.propose_action(&signer_a, &ProposalAction::Withdraw(signer_a.clone()));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += multisig::PROPOSAL_TTL + 1;
+    });
+
+    client.approve_action(&signer_b, &id);
+}
+
+// Test 18: Rotating the signer set drops approvals from removed signers
+#[test]
+fn test_multisig_rotate_signers() {
+    let env = Env::default();
+    let (client, owner, _) = setup_campaign(&env, 100_000_000);
+
+    let signer_a = Address::generate(&env);
+    let signer_b = Address::generate(&env);
+    let signer_c = Address::generate(&env);
+    let signers = soroban_sdk::vec![&env, signer_a.clone(), signer_b.clone()];
+    client.set_signers(&owner, &signers, &2);
+
+    // signer_a approves a withdrawal before being rotated out
+    let pending = client.propose_action(&signer_a, &ProposalAction::Withdraw(signer_a.clone()));
+
+    let new_signers = soroban_sdk::vec![&env, signer_b.clone(), signer_c.clone()];
+    let id = client.propose_action(
+        &signer_b,
+        &ProposalAction::SetSigners(new_signers.clone(), 2),
+    );
+    client.approve_action(&signer_a, &id);
+    client.execute_action(&signer_a, &id);
+
+    assert_eq!(client.get_signers(), new_signers);
+
+    // Only signer_b's approval would count now, and signer_a is no longer a signer
+    client.approve_action(&signer_b, &pending);
+    assert!(client.try_execute_action(&signer_b, &pending).is_err());
+}
+
+// Test 19: Ownership moves only after the new owner accepts
+#[test]
+fn test_two_step_ownership_transfer() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+    let new_owner = Address::generate(&env);
+
+    client.propose_owner_transfer(&owner, &new_owner);
+    assert_eq!(client.get_pending_owner(), Some(new_owner.clone()));
+    assert_eq!(client.get_campaign_info().0, owner);
+
+    client.accept_ownership(&new_owner);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "ownership_transferred"),
+            owner.clone(),
+            new_owner.clone()
+        )
+            .into_val(&env)
+    );
+
+    assert_eq!(client.get_pending_owner(), None);
+    assert_eq!(client.get_campaign_info().0, new_owner);
+
+    // The new owner controls the funds
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+    client.withdraw(&new_owner);
+    assert_eq!(token.balance(&new_owner), 100_000_000);
+}
+
+// Test 20: A pending owner cannot withdraw before accepting
+#[test]
+#[should_panic(expected = "Only campaign owner can withdraw")]
+fn test_pending_owner_cannot_withdraw() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign(&env, 100_000_000);
+    let new_owner = Address::generate(&env);
+
+    client.propose_owner_transfer(&owner, &new_owner);
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    client.withdraw(&new_owner);
+}
+
+// Test 21: A cancelled transfer can no longer be accepted
+#[test]
+#[should_panic(expected = "No pending ownership transfer for this address")]
+fn test_cancel_owner_transfer() {
+    let env = Env::default();
+    let (client, owner, _) = setup_campaign(&env, 100_000_000);
+    let new_owner = Address::generate(&env);
+
+    client.propose_owner_transfer(&owner, &new_owner);
+    client.cancel_owner_transfer(&owner);
+    assert_eq!(client.get_pending_owner(), None);
+
+    client.accept_ownership(&new_owner);
+}
+
+// Test 22: Multisig campaigns transfer ownership through a proposal
+#[test]
+fn test_multisig_ownership_transfer() {
+    let env = Env::default();
+    let (client, owner, _) = setup_campaign(&env, 100_000_000);
+    let signer_a = Address::generate(&env);
+    let new_owner = Address::generate(&env);
+
+    client.set_signers(&owner, &soroban_sdk::vec![&env, signer_a.clone()], &1);
+    assert!(client
+        .try_propose_owner_transfer(&owner, &new_owner)
+        .is_err());
+
+    let id = client.propose_action(
+        &signer_a,
+        &ProposalAction::TransferOwnership(new_owner.clone()),
+    );
+    client.execute_action(&signer_a, &id);
+    client.accept_ownership(&new_owner);
+
+    // The signer set is dissolved and the new owner acts alone
+    assert_eq!(client.get_threshold(), 0);
+    assert_eq!(client.get_campaign_info().0, new_owner);
+}
+
+// Helper: the platform contract the campaign was created on, and its admin
+fn setup_platform<'a>(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+) -> (platform::PlatformContractClient<'a>, Address) {
+    let platform_client = platform::PlatformContractClient::new(env, &client.get_platform());
+    let admin = platform_client.get_admin();
+
+    (platform_client, admin)
+}
+
+// Test 23: A platform-wide pause stops donations
+#[test]
+#[should_panic(expected = "Donations are paused")]
+fn test_platform_pause_blocks_donations() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let (platform_client, admin) = setup_platform(&env, &client);
+
+    platform_client.set_paused(&admin, &(Operation::Donate as u32), &true);
+    assert!(client.is_paused(&(Operation::Donate as u32)));
+    assert!(!client.is_paused(&(Operation::Withdraw as u32)));
+
+    donate_from_new_donor(&env, &client, &token_admin, 10_000_000);
+}
+
+// Test 24: A per-campaign pause stops withdrawals until lifted
+#[test]
+fn test_campaign_pause_blocks_withdraw() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign(&env, 100_000_000);
+    let (_, admin) = setup_platform(&env, &client);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    client.set_campaign_paused(&admin, &(Operation::Withdraw as u32), &true);
+    assert!(client.is_paused(&(Operation::Withdraw as u32)));
+    assert!(client.try_withdraw(&owner).is_err());
+
+    client.set_campaign_paused(&admin, &(Operation::Withdraw as u32), &false);
+    client.withdraw(&owner);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Withdrawn as u32
+    );
+}
+
+// Test 25: Refunds stay available while everything else is paused
+#[test]
+fn test_refund_available_while_paused() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+    let (platform_client, admin) = setup_platform(&env, &client);
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 30_000_000);
+
+    platform_client.set_paused(&admin, &(Operation::Donate as u32), &true);
+    platform_client.set_paused(&admin, &(Operation::Withdraw as u32), &true);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    client.refund(&donor);
+    assert_eq!(token.balance(&donor), 30_000_000);
+}
+
+// Test 26: Only the platform admin can pause a campaign
+#[test]
+#[should_panic(expected = "Only platform admin can perform this action")]
+fn test_campaign_pause_not_admin() {
+    let env = Env::default();
+    let (client, owner, _) = setup_campaign(&env, 100_000_000);
+    setup_platform(&env, &client);
+
+    client.set_campaign_paused(&owner, &(Operation::Donate as u32), &true);
+}
+
+// Test 27: Upgrade to freshly uploaded code without losing donations or funds
+#[test]
+fn test_upgrade_preserves_balances() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let (_, admin) = setup_platform(&env, &client);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor_a = donate_from_new_donor(&env, &client, &token_admin, 30_000_000);
+    let donor_b = donate_from_new_donor(&env, &client, &token_admin, 20_000_000);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(upgraded::WASM);
+    client.upgrade(&admin, &wasm_hash);
+
+    // Calls now run the uploaded code against the same storage
+    let upgraded = upgraded::Client::new(&env, &client.address);
+    assert_eq!(upgraded.get_schema_version(), 2);
+    assert_eq!(upgraded.get_donation(&donor_a), 30_000_000);
+    assert_eq!(upgraded.get_donation(&donor_b), 20_000_000);
+    assert_eq!(upgraded.get_total_raised(), 50_000_000);
+    assert_eq!(token.balance(&client.address), 50_000_000);
+
+    // Donors can still be refunded after the upgrade
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+    upgraded.refund(&donor_a);
+    assert_eq!(token.balance(&donor_a), 30_000_000);
+    assert_eq!(token.balance(&client.address), 20_000_000);
+}
+
+// Test 28: Only the platform admin can upgrade
+#[test]
+#[should_panic(expected = "Only platform admin can perform this action")]
+fn test_upgrade_not_admin() {
+    let env = Env::default();
+    let (client, owner, _) = setup_campaign(&env, 100_000_000);
+
+    let wasm_hash = soroban_sdk::BytesN::from_array(&env, &[0; 32]);
+    client.upgrade(&owner, &wasm_hash);
+}
+
+// Test 29: Campaigns created on the current layout have nothing to migrate
+#[test]
+#[should_panic(expected = "Storage already migrated")]
+fn test_migrate_current_schema() {
+    let env = Env::default();
+    let (client, _, _) = setup_campaign(&env, 100_000_000);
+
+    assert_eq!(client.get_schema_version(), 2);
+    client.migrate();
+}
+
+// Helper: mint tokens to a fresh donor, approve the campaign and pledge
+fn pledge_from_new_donor(
+    env: &Env,
+    client: &CrowdfundingContractClient,
+    token_admin: &token::StellarAssetClient,
+    amount: i128,
+) -> Address {
+    let donor = Address::generate(env);
+    token_admin.mint(&donor, &amount);
+    token::Client::new(env, &token_admin.address).approve(
+        &donor,
+        &client.address,
+        &amount,
+        &(env.ledger().sequence() + 1000),
+    );
+    client.pledge(&donor, &amount);
+    donor
+}
+
+// Test 30: Pledges are pulled in after the deadline when they reach the goal
+#[test]
+fn test_pledge_collect_on_success() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor_a = pledge_from_new_donor(&env, &client, &token_admin, 60_000_000);
+    let donor_b = pledge_from_new_donor(&env, &client, &token_admin, 50_000_000);
+
+    // Nothing moves while the campaign runs
+    assert_eq!(client.get_total_pledged(), 110_000_000);
+    assert_eq!(client.get_total_raised(), 0);
+    assert_eq!(token.balance(&donor_a), 60_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    // Collect in two batches
+    assert_eq!(client.collect(&1), 1);
+    assert_eq!(client.collect(&10), 1);
+
+    assert_eq!(client.get_total_raised(), 110_000_000);
+    assert_eq!(client.get_total_pledged(), 0);
+    assert_eq!(client.get_donation(&donor_b), 50_000_000);
+    assert_eq!(
+        client.get_pledge(&donor_a).status,
+        PledgeStatus::Collected as u32
+    );
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Successful as u32
+    );
+
+    client.withdraw(&owner);
+    assert_eq!(token.balance(&owner), 110_000_000);
+}
+
+// Test 31: Pledges that fall short of the goal never move funds
+#[test]
+fn test_pledge_failed_campaign_moves_nothing() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor = pledge_from_new_donor(&env, &client, &token_admin, 30_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    assert!(client.try_collect(&10).is_err());
+    assert_eq!(token.balance(&donor), 30_000_000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.get_pledge(&donor).status,
+        PledgeStatus::Pending as u32
+    );
+}
+
+// Test 32: A revoked allowance defaults the pledge and excludes it from the goal
+#[test]
+fn test_pledge_revoked_allowance_defaults() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor_a = pledge_from_new_donor(&env, &client, &token_admin, 60_000_000);
+    let donor_b = pledge_from_new_donor(&env, &client, &token_admin, 50_000_000);
+
+    // donor_b revokes the allowance before collection
+    token.approve(&donor_b, &client.address, &0, &env.ledger().sequence());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    assert_eq!(client.collect(&10), 2);
+    assert_eq!(
+        client.get_pledge(&donor_b).status,
+        PledgeStatus::Defaulted as u32
+    );
+    assert_eq!(client.get_total_defaulted(), 50_000_000);
+    assert_eq!(client.get_total_raised(), 60_000_000);
+    assert_eq!(client.get_campaign_status(), CampaignStatus::Failed as u32);
+    assert_eq!(token.balance(&donor_b), 50_000_000);
+
+    // The collected pledge is refundable like a donation
+    client.refund(&donor_a);
+    assert_eq!(token.balance(&donor_a), 60_000_000);
+}
+
+// Test 33: A pledge must be backed by an allowance
+#[test]
+#[should_panic(expected = "Allowance does not cover pledge")]
+fn test_pledge_without_allowance() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &10_000_000);
+    client.pledge(&donor, &10_000_000);
+}
+
+// Test 34: Donation messages and display names show up in the history
+#[test]
+fn test_donate_with_message_and_name() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let donor = Address::generate(&env);
+    let message = soroban_sdk::String::from_str(&env, "Good luck!");
+    let name = soroban_sdk::String::from_str(&env, "Budi");
+    token_admin.mint(&donor, &10_000_000);
+    client.donate_with_note(
+        &donor,
+        &10_000_000,
+        &Some(message.clone()),
+        &Some(name.clone()),
+        &false,
+    );
+
+    let history = client.get_donation_history(&0, &10);
+    assert_eq!(client.get_donation_count(), 1);
+    assert_eq!(history.len(), 1);
+    let entry = history.get(0).unwrap();
+    assert_eq!(entry.donor, Some(donor.clone()));
+    assert_eq!(entry.amount, 10_000_000);
+    assert_eq!(entry.message, Some(message));
+    assert_eq!(entry.display_name, Some(name));
+    assert_eq!(
+        client.get_all_donors(),
+        soroban_sdk::vec![&env, (Some(donor), 10_000_000)]
+    );
+}
+
+// Test 35: Anonymous donations are masked in public views
+#[test]
+fn test_anonymous_donation_masked() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let visible = donate_from_new_donor(&env, &client, &token_admin, 10_000_000);
+    let hidden = Address::generate(&env);
+    let message = soroban_sdk::String::from_str(&env, "From a friend");
+    token_admin.mint(&hidden, &20_000_000);
+    client.donate_with_note(
+        &hidden,
+        &20_000_000,
+        &Some(message.clone()),
+        &Some(soroban_sdk::String::from_str(&env, "Secret")),
+        &true,
+    );
+
+    let entry = client.get_donation_history(&1, &1).get(0).unwrap();
+    assert_eq!(entry.donor, None);
+    assert_eq!(entry.display_name, None);
+    assert_eq!(entry.message, Some(message));
+    assert_eq!(entry.amount, 20_000_000);
+
+    assert_eq!(
+        client.get_all_donors(),
+        soroban_sdk::vec![&env, (Some(visible), 10_000_000), (None, 20_000_000)]
+    );
+
+    // The contract still knows who donated, so refunds keep working
+    assert_eq!(client.get_donation(&hidden), 20_000_000);
+}
+
+// Test 36: Donation messages have a length limit
+#[test]
+#[should_panic(expected = "Message is too long")]
+fn test_donate_message_too_long() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &10_000_000);
+    let long_message = soroban_sdk::String::from_bytes(&env, &[b'a'; 281]);
+    client.donate_with_note(&donor, &10_000_000, &Some(long_message), &None, &false);
+}
+
+// Test 37: Display names have a length limit
+#[test]
+#[should_panic(expected = "Display name is too long")]
+fn test_donate_display_name_too_long() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &10_000_000);
+    let long_name = soroban_sdk::String::from_bytes(&env, &[b'a'; 33]);
+    client.donate_with_note(&donor, &10_000_000, &None, &Some(long_name), &false);
+}
+
+// Test 38: Every donation is kept as its own record
+#[test]
+fn test_donation_records() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 500_000_000);
+
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &30_000_000);
+    client.donate(&donor, &10_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 60;
+        li.sequence_number += 12;
+    });
+    let other = donate_from_new_donor(&env, &client, &token_admin, 5_000_000);
+    client.donate(&donor, &20_000_000);
+
+    // The aggregate is kept for refunds
+    assert_eq!(client.get_donation(&donor), 30_000_000);
+
+    let first = client.get_donation_record(&0);
+    assert_eq!(first.id, 0);
+    assert_eq!(first.donor, Some(donor.clone()));
+    assert_eq!(first.amount, 10_000_000);
+    let second = client.get_donation_record(&1);
+    assert_eq!(second.donor, Some(other));
+    assert_eq!(second.timestamp, first.timestamp + 60);
+    assert_eq!(second.ledger, first.ledger + 12);
+
+    let mine = client.get_donations_by_donor(&donor, &0, &10);
+    assert_eq!(mine.len(), 2);
+    assert_eq!(mine.get(0).unwrap().id, 0);
+    assert_eq!(mine.get(1).unwrap().id, 2);
+    assert_eq!(mine.get(1).unwrap().amount, 20_000_000);
+
+    // Paging through a donor's donations
+    let page = client.get_donations_by_donor(&donor, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 2);
+}
+
+// Test 39: Recent donations come newest first
+#[test]
+fn test_recent_donations() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 500_000_000);
+
+    donate_from_new_donor(&env, &client, &token_admin, 1_000_000);
+    donate_from_new_donor(&env, &client, &token_admin, 2_000_000);
+    donate_from_new_donor(&env, &client, &token_admin, 3_000_000);
+
+    let recent = client.get_recent_donations(&2);
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent.get(0).unwrap().amount, 3_000_000);
+    assert_eq!(recent.get(1).unwrap().amount, 2_000_000);
+
+    assert_eq!(client.get_recent_donations(&10).len(), 3);
+}
+
+// Test 40: Looking up a donation that doesn't exist
+#[test]
+#[should_panic(expected = "Donation not found")]
+fn test_donation_record_not_found() {
+    let env = Env::default();
+    let (client, _, _) = setup_campaign(&env, 100_000_000);
+
+    client.get_donation_record(&0);
+}
+
+// Test 41: A gift is credited to the beneficiary, not the payer
+#[test]
+fn test_donate_for_credits_beneficiary() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let payer = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    token_admin.mint(&payer, &20_000_000);
+
+    client.donate_for(&payer, &beneficiary, &20_000_000);
+
+    assert_eq!(token.balance(&payer), 0);
+    assert_eq!(client.get_donation(&beneficiary), 20_000_000);
+    assert_eq!(client.get_donation(&payer), 0);
+    assert_eq!(client.get_refund_destination(&beneficiary), beneficiary);
+
+    let records = client.get_donations_by_donor(&beneficiary, &0, &10);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records.get(0).unwrap().payer, payer);
+    assert_eq!(records.get(0).unwrap().donor, beneficiary);
+}
+
+// Test 42: Refunds of a gift go to the destination the beneficiary chose
+#[test]
+fn test_gift_refund_to_destination() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let payer = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    token_admin.mint(&payer, &20_000_000);
+    client.donate_for(&payer, &beneficiary, &20_000_000);
+
+    // Without a choice the refund would go to the beneficiary; send it back instead
+    client.set_refund_destination(&beneficiary, &payer);
+    assert_eq!(client.get_refund_destination(&beneficiary), payer);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    client.refund(&beneficiary);
+    assert_eq!(token.balance(&payer), 20_000_000);
+    assert_eq!(token.balance(&beneficiary), 0);
+    assert_eq!(client.get_donation(&beneficiary), 0);
+}
+
+// Test 43: The payer of a gift has no refund rights of their own
+#[test]
+#[should_panic(expected = "No donation found for this address")]
+fn test_gift_payer_cannot_refund() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let payer = Address::generate(&env);
+    let beneficiary = Address::generate(&env);
+    token_admin.mint(&payer, &20_000_000);
+    client.donate_for(&payer, &beneficiary, &20_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    client.refund(&payer);
+}
+
+// Test 44: Sponsor matching counts toward the goal and unused matching goes back
+#[test]
+fn test_matching_pool_success() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    // Match 1:1 up to 3 XLM, with 5 XLM escrowed
+    let sponsor = Address::generate(&env);
+    token_admin.mint(&sponsor, &50_000_000);
+    let pool_id = client.add_matching_pool(&sponsor, &50_000_000, &10_000, &30_000_000);
+    assert_eq!(token.balance(&sponsor), 0);
+
+    donate_from_new_donor(&env, &client, &token_admin, 20_000_000);
+    assert_eq!(client.get_total_raised(), 40_000_000);
+
+    // Only 1 XLM of matching room is left for this one
+    donate_from_new_donor(&env, &client, &token_admin, 50_000_000);
+    assert_eq!(client.get_total_raised(), 100_000_000);
+    assert_eq!(client.get_total_matched(), 30_000_000);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Successful as u32
+    );
+
+    client.withdraw(&owner);
+    assert_eq!(token.balance(&owner), 100_000_000);
+
+    assert_eq!(client.return_matching(&pool_id), 20_000_000);
+    assert_eq!(token.balance(&sponsor), 20_000_000);
+    assert!(client.get_matching_pool(&pool_id).returned);
+}
+
+// Test 45: A failed campaign returns the whole matching deposit to the sponsor
+#[test]
+fn test_matching_pool_failed_campaign() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    // Match at 50%
+    let sponsor = Address::generate(&env);
+    token_admin.mint(&sponsor, &50_000_000);
+    let pool_id = client.add_matching_pool(&sponsor, &50_000_000, &5_000, &50_000_000);
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 20_000_000);
+    assert_eq!(client.get_total_raised(), 30_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    assert_eq!(client.return_matching(&pool_id), 50_000_000);
+    assert_eq!(token.balance(&sponsor), 50_000_000);
+    assert_eq!(client.get_total_raised(), 20_000_000);
+
+    client.refund(&donor);
+    assert_eq!(token.balance(&donor), 20_000_000);
+    assert_eq!(client.get_total_raised(), 0);
+}
+
+// Test 46: Matching can't be returned while donations are still being matched
+#[test]
+#[should_panic(expected = "Campaign is still active")]
+fn test_return_matching_while_active() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let sponsor = Address::generate(&env);
+    token_admin.mint(&sponsor, &50_000_000);
+    let pool_id = client.add_matching_pool(&sponsor, &50_000_000, &10_000, &50_000_000);
+
+    client.return_matching(&pool_id);
+}
+
+// Test 47: Anyone can push refunds to donors in batches
+#[test]
+fn test_process_refunds_in_batches() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor_a = donate_from_new_donor(&env, &client, &token_admin, 10_000_000);
+    let donor_b = donate_from_new_donor(&env, &client, &token_admin, 20_000_000);
+    let donor_c = donate_from_new_donor(&env, &client, &token_admin, 30_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    assert_eq!(client.process_refunds(&2), 2);
+    assert_eq!(client.get_refund_cursor(), 2);
+    assert_eq!(token.balance(&donor_a), 10_000_000);
+    assert_eq!(token.balance(&donor_b), 20_000_000);
+    assert!(client.is_refunded(&donor_b));
+    assert!(!client.is_refunded(&donor_c));
+
+    assert_eq!(client.process_refunds(&2), 1);
+    assert_eq!(token.balance(&donor_c), 30_000_000);
+    assert_eq!(client.get_total_raised(), 0);
+
+    // Nothing left to do
+    assert_eq!(client.process_refunds(&2), 0);
+}
+
+// Test 48: A donor who claimed their refund is skipped and can't claim again
+#[test]
+#[should_panic(expected = "Donation already refunded")]
+fn test_refund_after_crank() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor_a = donate_from_new_donor(&env, &client, &token_admin, 10_000_000);
+    let donor_b = donate_from_new_donor(&env, &client, &token_admin, 20_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    client.refund(&donor_a);
+    assert_eq!(client.process_refunds(&10), 1);
+    assert_eq!(token.balance(&donor_a), 10_000_000);
+    assert_eq!(token.balance(&donor_b), 20_000_000);
+
+    client.refund(&donor_b);
+}
+
+// Test 49: The refund crank only runs for failed campaigns
+#[test]
+#[should_panic(expected = "Refunds only available for failed campaigns")]
+fn test_process_refunds_active_campaign() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    donate_from_new_donor(&env, &client, &token_admin, 10_000_000);
+
+    client.process_refunds(&10);
+}
+
+// Helper: a failed campaign with two donors and a 7 day refund claim period
+fn setup_failed_with_policy<'a>(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'a>,
+    token::StellarAssetClient<'a>,
+    Address,
+    Address,
+    Address,
+) {
+    let fallback = Address::generate(env);
+    let (client, _, token_admin) = setup_campaign_with(
+        env,
+        100_000_000,
+        CampaignFeatures {
+            refund_fallback: Some(fallback.clone()),
+            refund_claim_period: 7 * 86400,
+            ..Default::default()
+        },
+    );
+
+    let donor_a = donate_from_new_donor(env, &client, &token_admin, 10_000_000);
+    let donor_b = donate_from_new_donor(env, &client, &token_admin, 20_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    (client, token_admin, fallback, donor_a, donor_b)
+}
+
+// Test 50: Unclaimed refunds are swept to the fallback after the claim period
+#[test]
+fn test_sweep_unclaimed_refunds() {
+    let env = Env::default();
+    let (client, token_admin, fallback, donor_a, donor_b) = setup_failed_with_policy(&env);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    client.refund(&donor_a);
+    assert_eq!(token.balance(&donor_a), 10_000_000);
+
+    // The claim period runs from the campaign deadline
+    let claim_deadline = client.get_refund_claim_deadline().unwrap();
+    assert_eq!(claim_deadline, client.get_campaign_info().2 + 7 * 86400);
+    env.ledger().with_mut(|li| {
+        li.timestamp = claim_deadline + 1;
+    });
+
+    assert_eq!(client.sweep_unclaimed(), 20_000_000);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "unclaimed_funds_swept"),
+            fallback.clone()
+        )
+            .into_val(&env)
+    );
+
+    assert_eq!(token.balance(&fallback), 20_000_000);
+    assert_eq!(token.balance(&donor_b), 0);
+    assert_eq!(client.get_total_raised(), 0);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Archived as u32
+    );
+}
+
+// Test 51: Nothing can be swept while donors can still claim
+#[test]
+#[should_panic(expected = "Refund claim period has not ended")]
+fn test_sweep_during_claim_period() {
+    let env = Env::default();
+    let (client, _, _, _, _) = setup_failed_with_policy(&env);
+
+    client.sweep_unclaimed();
+}
+
+// Test 52: Refunds end once the campaign is archived
+#[test]
+#[should_panic(expected = "Refunds only available for failed campaigns")]
+fn test_refund_after_sweep() {
+    let env = Env::default();
+    let (client, _, _, _, donor_b) = setup_failed_with_policy(&env);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 7 * 86400 + 1;
+    });
+    client.sweep_unclaimed();
+
+    client.refund(&donor_b);
+}
+
+// Test 53: The claim period can't be shorter than the minimum
+#[test]
+#[should_panic(expected = "Claim period is too short")]
+fn test_refund_policy_too_short() {
+    let env = Env::default();
+    setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            refund_fallback: Some(Address::generate(&env)),
+            refund_claim_period: 3600,
+            ..Default::default()
+        },
+    );
+}
+
+// Test 54: Donors can take back part of a donation while the campaign is under goal
+#[test]
+fn test_unpledge_while_active() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 30_000_000);
+
+    client.unpledge(&donor, &10_000_000);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "donation_withdrawn"),
+            donor.clone()
+        )
+            .into_val(&env)
+    );
+
+    assert_eq!(token.balance(&donor), 10_000_000);
+    assert_eq!(client.get_donation(&donor), 20_000_000);
+    assert_eq!(client.get_total_raised(), 20_000_000);
+}
+
+// Test 55: Without a cool-off, donations are locked in once the goal is reached
+#[test]
+#[should_panic(expected = "Donation can no longer be withdrawn")]
+fn test_unpledge_after_goal() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    client.unpledge(&donor, &10_000_000);
+}
+
+// Test 56: During the cool-off a donor can take a donation back past the goal,
+// which reopens the campaign and holds off the withdrawal
+#[test]
+fn test_unpledge_during_cool_off() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            cool_off: 3600,
+            ..Default::default()
+        },
+    );
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Successful as u32
+    );
+    assert!(client.try_withdraw(&owner).is_err());
+
+    client.unpledge(&donor, &40_000_000);
+    assert_eq!(client.get_total_raised(), 60_000_000);
+    assert_eq!(client.get_campaign_status(), CampaignStatus::Active as u32);
+}
+
+// Test 57: A donor can't take back more than they gave
+#[test]
+#[should_panic(expected = "Amount exceeds donation")]
+fn test_unpledge_more_than_donated() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 30_000_000);
+
+    client.unpledge(&donor, &40_000_000);
+}
+
+// Test 58: A vested campaign pays the owner linearly after the cliff
+#[test]
+fn test_claim_vested_linear() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            vesting: VestingSchedule {
+                cliff: 86400,
+                duration: 10 * 86400,
+            },
+            ..Default::default()
+        },
+    );
+    let token = token::Client::new(&env, &token_admin.address);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+    let start = env.ledger().timestamp();
+
+    // The lump-sum withdrawal is off, and nothing is claimable before the cliff
+    assert!(client.try_withdraw(&owner).is_err());
+    assert!(client.try_claim_vested(&owner).is_err());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = start + 5 * 86400;
+    });
+    assert_eq!(client.claim_vested(&owner), 50_000_000);
+    assert_eq!(token.balance(&owner), 50_000_000);
+
+    let status = client.get_vesting_status();
+    assert_eq!(status.start, Some(start));
+    assert_eq!(status.total, 100_000_000);
+    assert_eq!(status.claimed, 50_000_000);
+    assert_eq!(status.claimable, 0);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = start + 20 * 86400;
+    });
+    assert_eq!(client.claim_vested(&owner), 50_000_000);
+    assert_eq!(token.balance(&owner), 100_000_000);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Withdrawn as u32
+    );
+}
+
+// Test 59: A cliff past the end of the schedule is rejected
+#[test]
+#[should_panic(expected = "Invalid vesting schedule")]
+fn test_vesting_invalid_schedule() {
+    let env = Env::default();
+    setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            vesting: VestingSchedule {
+                cliff: 2 * 86400,
+                duration: 86400,
+            },
+            ..Default::default()
+        },
+    );
+}
+
+// Test 60: Nothing vests while the campaign hasn't succeeded
+#[test]
+#[should_panic(expected = "Campaign is not successful")]
+fn test_claim_vested_failed_campaign() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            vesting: VestingSchedule {
+                cliff: 0,
+                duration: 86400,
+            },
+            ..Default::default()
+        },
+    );
+
+    donate_from_new_donor(&env, &client, &token_admin, 10_000_000);
+    assert_eq!(client.get_vesting_status().vested, 0);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    client.claim_vested(&owner);
+}
+
+// Helper: a campaign with an arbiter and a 3 day challenge window that has just
+// reached its goal through two donors
+fn setup_arbitrated_campaign<'a>(
+    env: &Env,
+) -> (
+    CrowdfundingContractClient<'a>,
+    Address,
+    token::StellarAssetClient<'a>,
+    Address,
+    Address,
+    Address,
+) {
+    let arbiter = Address::generate(env);
+    let (client, owner, token_admin) = setup_campaign_with(
+        env,
+        100_000_000,
+        CampaignFeatures {
+            arbiter: Some(arbiter.clone()),
+            challenge_window: 3 * 86400,
+            ..Default::default()
+        },
+    );
+
+    let donor_a = donate_from_new_donor(env, &client, &token_admin, 60_000_000);
+    let donor_b = donate_from_new_donor(env, &client, &token_admin, 40_000_000);
+
+    (client, owner, token_admin, arbiter, donor_a, donor_b)
+}
+
+// Test 61: A dispute freezes the payout until the arbiter releases it
+#[test]
+fn test_dispute_released_by_arbiter() {
+    let env = Env::default();
+    let (client, owner, token_admin, arbiter, donor_a, _) = setup_arbitrated_campaign(&env);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let evidence = soroban_sdk::String::from_str(&env, "ipfs://evidence");
+    let id = client.open_dispute(&donor_a, &evidence);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "dispute_opened"),
+            id,
+            donor_a.clone()
+        )
+            .into_val(&env)
+    );
+    assert_eq!(client.get_dispute(&id).evidence, evidence);
+
+    // Still frozen after the challenge window while the dispute is open
+    env.ledger().with_mut(|li| {
+        li.timestamp += 4 * 86400;
+    });
+    assert!(client.try_withdraw(&owner).is_err());
+
+    client.resolve_dispute(&arbiter, &0);
+    assert_eq!(
+        client.get_arbitration_state(),
+        ArbitrationState::Resolved as u32
+    );
+
+    client.withdraw(&owner);
+    assert_eq!(token.balance(&owner), 100_000_000);
+}
+
+// Test 62: The arbiter can send part of the funds back to donors pro-rata
+#[test]
+fn test_dispute_refunds_pro_rata() {
+    let env = Env::default();
+    let (client, owner, token_admin, arbiter, donor_a, donor_b) = setup_arbitrated_campaign(&env);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    client.open_dispute(
+        &donor_b,
+        &soroban_sdk::String::from_str(&env, "sha256:ab12"),
+    );
+    client.resolve_dispute(&arbiter, &5_000);
+
+    assert_eq!(client.get_dispute_refund(&donor_a), 30_000_000);
+    assert_eq!(client.claim_dispute_refund(&donor_a), 30_000_000);
+    assert_eq!(client.claim_dispute_refund(&donor_b), 20_000_000);
+    assert_eq!(token.balance(&donor_a), 30_000_000);
+    assert_eq!(token.balance(&donor_b), 20_000_000);
+    assert_eq!(client.get_dispute_refund(&donor_a), 0);
+    assert_eq!(client.get_donation(&donor_a), 30_000_000);
+    assert_eq!(client.get_donation(&donor_b), 20_000_000);
+
+    client.withdraw(&owner);
+    assert_eq!(token.balance(&owner), 50_000_000);
+}
+
+// Test 63: The owner can't withdraw while donors can still challenge
+#[test]
+#[should_panic(expected = "Challenge window is still open")]
+fn test_withdraw_during_challenge_window() {
+    let env = Env::default();
+    let (client, owner, _, _, _, _) = setup_arbitrated_campaign(&env);
+
+    client.withdraw(&owner);
+}
+
+// Test 64: Disputes can only be opened during the challenge window
+#[test]
+#[should_panic(expected = "Challenge window has closed")]
+fn test_dispute_after_challenge_window() {
+    let env = Env::default();
+    let (client, _, _, _, donor_a, _) = setup_arbitrated_campaign(&env);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 3 * 86400 + 1;
+    });
+
+    client.open_dispute(
+        &donor_a,
+        &soroban_sdk::String::from_str(&env, "ipfs://late"),
+    );
+}
+
+// Test 65: A time-locked withdrawal is announced, then paid out after the delay
+#[test]
+fn test_timelocked_withdrawal() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            withdrawal_delay: 2 * 86400,
+            ..Default::default()
+        },
+    );
+    let token = token::Client::new(&env, &token_admin.address);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    // The instant withdrawal is off
+    assert!(client.try_withdraw(&owner).is_err());
+
+    let release_at = client.request_withdrawal(&owner);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "withdrawal_requested"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+    assert_eq!(release_at, env.ledger().timestamp() + 2 * 86400);
+    assert!(client.try_execute_withdrawal(&owner).is_err());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = release_at;
+    });
+    client.execute_withdrawal(&owner);
+
+    assert_eq!(token.balance(&owner), 100_000_000);
+    assert_eq!(client.get_pending_withdrawal(), None);
+}
+
+// Test 66: The platform admin can veto an announced withdrawal
+#[test]
+#[should_panic(expected = "No withdrawal requested")]
+fn test_withdrawal_vetoed() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            withdrawal_delay: 2 * 86400,
+            ..Default::default()
+        },
+    );
+    let (_, admin) = setup_platform(&env, &client);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    client.request_withdrawal(&owner);
+    client.veto_withdrawal(
+        &admin,
+        &soroban_sdk::String::from_str(&env, "Fraud reported"),
+    );
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 2 * 86400;
+    });
+    client.execute_withdrawal(&owner);
+}
+
+// Test 67: Only the platform admin can veto
+#[test]
+#[should_panic(expected = "Only platform admin can perform this action")]
+fn test_veto_withdrawal_not_admin() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            withdrawal_delay: 86400,
+            ..Default::default()
+        },
+    );
+    setup_platform(&env, &client);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    client.request_withdrawal(&owner);
+    client.veto_withdrawal(&owner, &soroban_sdk::String::from_str(&env, "No reason"));
+}
+
+// Test 68: Revenue is shared pro rata and each backer claims only what's new
+#[test]
+fn test_revenue_sharing() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            revenue_sharing: true,
+            ..Default::default()
+        },
+    );
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor_a = donate_from_new_donor(&env, &client, &token_admin, 75_000_000);
+    let donor_b = donate_from_new_donor(&env, &client, &token_admin, 25_000_000);
+    client.withdraw(&owner);
+
+    token_admin.mint(&owner, &40_000_000);
+    client.deposit_revenue(&owner, &40_000_000);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "revenue_deposited"),
+            owner.clone()
+        )
+            .into_val(&env)
+    );
+
+    assert_eq!(client.claim_revenue(&donor_a), 30_000_000);
+    assert_eq!(client.get_claimable_revenue(&donor_b), 10_000_000);
+
+    client.deposit_revenue(&owner, &20_000_000);
+    assert_eq!(client.claim_revenue(&donor_a), 15_000_000);
+    assert_eq!(client.claim_revenue(&donor_b), 15_000_000);
+    assert_eq!(token.balance(&donor_a), 45_000_000);
+    assert_eq!(client.get_revenue_info(), (true, 60_000_000));
+}
+
+// Test 69: A backer can't claim the same revenue twice
+#[test]
+#[should_panic(expected = "No revenue to claim")]
+fn test_claim_revenue_twice() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            revenue_sharing: true,
+            ..Default::default()
+        },
+    );
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    token_admin.mint(&owner, &10_000_000);
+    client.deposit_revenue(&owner, &10_000_000);
+    client.claim_revenue(&donor);
+    client.claim_revenue(&donor);
+}
+
+// Test 70: No revenue can be shared before the campaign succeeds
+#[test]
+#[should_panic(expected = "Campaign is not successful")]
+fn test_deposit_revenue_before_success() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            revenue_sharing: true,
+            ..Default::default()
+        },
+    );
+
+    donate_from_new_donor(&env, &client, &token_admin, 50_000_000);
+
+    token_admin.mint(&owner, &10_000_000);
+    client.deposit_revenue(&owner, &10_000_000);
+}
+
+// Test 71: Lenders are repaid principal plus interest, pro rata
+#[test]
+fn test_loan_repaid_to_lenders() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            // 10% interest, repaid in two monthly installments
+            loan: LoanTerms {
+                interest_bps: 1_000,
+                installments: 2,
+                interval: 30 * 86400,
+            },
+            ..Default::default()
+        },
+    );
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let lender_a = donate_from_new_donor(&env, &client, &token_admin, 60_000_000);
+    let lender_b = donate_from_new_donor(&env, &client, &token_admin, 40_000_000);
+    client.withdraw(&owner);
+
+    let status = client.get_loan_status();
+    assert_eq!(status.total_owed, 110_000_000);
+    assert_eq!(status.due, 0);
+
+    token_admin.mint(&owner, &10_000_000);
+    client.repay(&owner, &55_000_000);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (soroban_sdk::Symbol::new(&env, "loan_repaid"), owner.clone()).into_val(&env)
+    );
+    assert_eq!(client.claim_repayment(&lender_a), 33_000_000);
+
+    client.repay(&owner, &55_000_000);
+    assert_eq!(client.claim_repayment(&lender_a), 33_000_000);
+    assert_eq!(client.claim_repayment(&lender_b), 44_000_000);
+    assert_eq!(token.balance(&lender_a), 66_000_000);
+    assert_eq!(client.get_claimable_repayment(&lender_b), 0);
+}
+
+// Test 72: A missed installment flags the campaign as defaulted until caught up
+#[test]
+fn test_loan_default() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            loan: LoanTerms {
+                interest_bps: 0,
+                installments: 4,
+                interval: 86400,
+            },
+            ..Default::default()
+        },
+    );
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+    client.withdraw(&owner);
+
+    client.repay(&owner, &25_000_000);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 2 * 86400;
+    });
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Defaulted as u32
+    );
+    assert_eq!(client.check_default(), CampaignStatus::Defaulted as u32);
+    assert!(client.get_loan_status().defaulted);
+
+    client.repay(&owner, &25_000_000);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Withdrawn as u32
+    );
+}
+
+// Test 73: The owner can't repay more than is owed
+#[test]
+#[should_panic(expected = "Amount exceeds what is owed")]
+fn test_loan_overpaid() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            loan: LoanTerms {
+                interest_bps: 500,
+                installments: 1,
+                interval: 86400,
+            },
+            ..Default::default()
+        },
+    );
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+    client.withdraw(&owner);
+
+    client.repay(&owner, &105_000_001);
+}
+
+// Helper: a presale campaign selling 5 project tokens per XLM, with 1,000
+// tokens escrowed and an optional sale cap
+fn setup_presale<'a>(
+    env: &Env,
+    goal: i128,
+    sale_cap: i128,
+) -> (
+    CrowdfundingContractClient<'a>,
+    Address,
+    token::StellarAssetClient<'a>,
+    token::Client<'a>,
+) {
+    let owner = Address::generate(env);
+    let project_token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &project_token)
+        .mock_all_auths()
+        .mint(&owner, &10_000_000_000);
+
+    let (client, owner, token_admin) = setup_campaign_as(
+        env,
+        &owner,
+        goal,
+        CampaignFeatures {
+            presale_token: Some(project_token.clone()),
+            presale_rate: 50_000_000,
+            presale_amount: 10_000_000_000,
+            sale_cap,
+            ..Default::default()
+        },
+    );
+    (
+        client,
+        owner,
+        token_admin,
+        token::Client::new(env, &project_token),
+    )
+}
+
+// Test 74: Backers claim project tokens for what they paid, the owner gets the rest
+#[test]
+fn test_presale_claim_tokens() {
+    let env = Env::default();
+    let (client, owner, token_admin, project_token) = setup_presale(&env, 100_000_000, 0);
+
+    let donor_a = donate_from_new_donor(&env, &client, &token_admin, 60_000_000);
+    let donor_b = donate_from_new_donor(&env, &client, &token_admin, 40_000_000);
+
+    assert_eq!(client.claim_tokens(&donor_a), 300_000_000);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "tokens_claimed"),
+            donor_a.clone()
+        )
+            .into_val(&env)
+    );
+    assert_eq!(client.get_claimable_tokens(&donor_a), 0);
+    assert_eq!(client.get_claimable_tokens(&donor_b), 200_000_000);
+
+    assert_eq!(client.reclaim_presale_tokens(&owner), 9_500_000_000);
+    client.claim_tokens(&donor_b);
+    assert_eq!(project_token.balance(&donor_b), 200_000_000);
+    assert_eq!(project_token.balance(&owner), 9_500_000_000);
+}
+
+// Test 75: On failure backers are refunded and the owner takes every token back
+#[test]
+fn test_presale_failed() {
+    let env = Env::default();
+    let (client, owner, token_admin, project_token) = setup_presale(&env, 100_000_000, 0);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 30_000_000);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    client.refund(&donor);
+    assert_eq!(token.balance(&donor), 30_000_000);
+    assert_eq!(client.reclaim_presale_tokens(&owner), 10_000_000_000);
+    assert_eq!(project_token.balance(&owner), 10_000_000_000);
+}
+
+// Test 76: Donations can't buy more tokens than were escrowed
+#[test]
+#[should_panic(expected = "Not enough presale tokens left")]
+fn test_presale_sold_out() {
+    let env = Env::default();
+    let (client, _, token_admin, _) = setup_presale(&env, 5_000_000_000, 0);
+
+    donate_from_new_donor(&env, &client, &token_admin, 2_000_000_001);
+}
+
+// Test 77: An oversubscribed sale keeps the cap and refunds the rest pro rata,
+// rounding every refund and allocation down
+#[test]
+fn test_oversubscribed_sale() {
+    let env = Env::default();
+    let (client, owner, token_admin, project_token) = setup_presale(&env, 100_000_000, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+    let donor_a = donate_from_new_donor(&env, &client, &token_admin, 70_000_001);
+    let donor_b = donate_from_new_donor(&env, &client, &token_admin, 50_000_000);
+    let donor_c = donate_from_new_donor(&env, &client, &token_admin, 30_000_002);
+
+    // Past the goal, the sale stays open until the deadline
+    assert_eq!(client.get_campaign_status(), CampaignStatus::Active as u32);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    client.withdraw(&owner);
+    assert_eq!(token.balance(&owner), 100_000_000);
+
+    // 50_000_003 oversubscribed out of 150_000_003
+    assert_eq!(client.get_allocation(&donor_a), (46_666_667, 23_333_334));
+    assert_eq!(client.get_allocation(&donor_b), (33_333_333, 16_666_667));
+    assert_eq!(client.get_allocation(&donor_c), (20_000_001, 10_000_001));
+
+    assert_eq!(client.claim_tokens(&donor_a), 233_333_332);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "tokens_claimed"),
+            donor_a.clone()
+        )
+            .into_val(&env)
+    );
+    let data: soroban_sdk::Map<soroban_sdk::Symbol, i128> = data.into_val(&env);
+    assert_eq!(
+        data.get(soroban_sdk::Symbol::new(&env, "refund")),
+        Some(23_333_334)
+    );
+
+    assert_eq!(client.claim_tokens(&donor_b), 166_666_663);
+    assert_eq!(client.claim_tokens(&donor_c), 100_000_004);
+    assert_eq!(token.balance(&donor_b), 16_666_667);
+    assert_eq!(project_token.balance(&donor_c), 100_000_004);
+
+    // One unit of rounding dust is all that's left behind
+    assert_eq!(token.balance(&client.address), 1);
+}
+
+// Test 78: An undersubscribed capped sale keeps every contribution
+#[test]
+fn test_capped_sale_under_cap() {
+    let env = Env::default();
+    let (client, owner, token_admin, _) = setup_presale(&env, 100_000_000, 200_000_000);
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 150_000_000);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    assert_eq!(client.get_allocation(&donor), (150_000_000, 0));
+    assert_eq!(client.claim_tokens(&donor), 750_000_000);
+    assert_eq!(client.reclaim_presale_tokens(&owner), 9_250_000_000);
+}
+
+// Test 79: The escrow must cover everything the cap can sell
+#[test]
+#[should_panic(expected = "Escrowed tokens do not cover the cap")]
+fn test_sale_cap_over_escrow() {
+    let env = Env::default();
+    setup_presale(&env, 100_000_000, 2_000_000_001);
+}
+
+// Helper: a successful campaign with donors of 1, 3 and 6 XLM (0.1 XLM minimum)
+fn setup_raffle_campaign<'a>(env: &Env) -> (CrowdfundingContractClient<'a>, Address, [Address; 3]) {
+    let (client, owner, token_admin) = setup_campaign(env, 100_000_000);
+
+    let donors = [
+        donate_from_new_donor(env, &client, &token_admin, 10_000_000),
+        donate_from_new_donor(env, &client, &token_admin, 30_000_000),
+        donate_from_new_donor(env, &client, &token_admin, 60_000_000),
+    ];
+    (client, owner, donors)
+}
+
+// Test 80: A seeded draw picks the same distinct winners every time
+#[test]
+fn test_draw_winners_seeded() {
+    let draw = |seed: u8| {
+        let env = Env::default();
+        let (client, owner, donors) = setup_raffle_campaign(&env);
+        let winners = env.as_contract(&client.address, || {
+            env.prng()
+                .seed(soroban_sdk::Bytes::from_array(&env, &[seed; 32]));
+            CrowdfundingContract::draw_winners(env.clone(), owner.clone(), 2, false)
+        });
+
+        assert_eq!(client.get_raffle_winners(), winners);
+        assert_eq!(winners.len(), 2);
+        assert_ne!(winners.get(0), winners.get(1));
+        let place = |i| {
+            let winner = winners.get(i).unwrap();
+            donors.iter().position(|donor| *donor == winner).unwrap()
+        };
+        [place(0), place(1)]
+    };
+
+    assert_eq!(draw(1), draw(1));
+    assert_eq!(draw(1), [2, 1]);
+    assert_eq!(draw(2), [1, 2]);
+    assert_eq!(draw(10), [0, 2]);
+}
+
+// Test 81: Bigger contributions win more often
+#[test]
+fn test_draw_winners_weighted() {
+    let env = Env::default();
+    let (client, owner, donors) = setup_raffle_campaign(&env);
+
+    let mut wins = [0u32; 3];
+    for seed in 0..60u8 {
+        let winner = env.as_contract(&client.address, || {
+            env.prng()
+                .seed(soroban_sdk::Bytes::from_array(&env, &[seed; 32]));
+            CrowdfundingContract::draw_winners(env.clone(), owner.clone(), 1, true)
+                .get(0)
+                .unwrap()
+        });
+        wins[donors.iter().position(|donor| *donor == winner).unwrap()] += 1;
+
+        // Clear the draw so the next seed can run
+        env.as_contract(&client.address, || {
+            env.storage().instance().remove(&symbol_short!("raffle"));
+        });
+    }
+
+    assert!(wins[2] > wins[1] && wins[1] > wins[0]);
+}
+
+// Test 82: The raffle can only be drawn once
+#[test]
+#[should_panic(expected = "Winners already drawn")]
+fn test_draw_winners_twice() {
+    let env = Env::default();
+    let (client, owner, _) = setup_raffle_campaign(&env);
+
+    client.draw_winners(&owner, &1, &false);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "winner_drawn"),
+            client.get_raffle_winners().get(0).unwrap()
+        )
+            .into_val(&env)
+    );
+
+    client.draw_winners(&owner, &1, &false);
+}
+
+// Test 83: An announced campaign is Upcoming and opens at its start time
+#[test]
+fn test_scheduled_start() {
+    let env = Env::default();
+    let start_time = env.ledger().timestamp() + 3600;
+    let (client, _, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            start_time,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(client.get_start_time(), start_time);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Upcoming as u32
+    );
+    assert_eq!(
+        client.get_campaign_info().7,
+        CampaignStatus::Upcoming as u32
+    );
+
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &10_000_000);
+    let result = client.try_donate(&donor, &10_000_000);
+    assert!(result.is_err());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = start_time;
+    });
+    assert_eq!(client.get_campaign_status(), CampaignStatus::Active as u32);
+    client.donate(&donor, &10_000_000);
+    assert_eq!(client.get_total_raised(), 10_000_000);
+}
+
+// Test 84: The start time must come before the deadline
+#[test]
+#[should_panic(expected = "Start time must be before the deadline")]
+fn test_start_time_after_deadline() {
+    let env = Env::default();
+    setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            start_time: env.ledger().timestamp() + 86400,
+            ..Default::default()
+        },
+    );
+}
+
+// Test 85: A donor refunded by the arbiter can't also take their donation back
+#[test]
+#[should_panic(expected = "Donation already refunded")]
+fn test_unpledge_after_dispute_refund() {
+    let env = Env::default();
+    let arbiter = Address::generate(&env);
+    let (client, _, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            arbiter: Some(arbiter.clone()),
+            challenge_window: 3 * 86400,
+            cool_off: 86400,
+            ..Default::default()
+        },
+    );
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 60_000_000);
+    donate_from_new_donor(&env, &client, &token_admin, 40_000_000);
+
+    client.open_dispute(&donor, &soroban_sdk::String::from_str(&env, "ipfs://a"));
+    client.resolve_dispute(&arbiter, &5_000);
+    assert_eq!(client.claim_dispute_refund(&donor), 30_000_000);
+
+    client.unpledge(&donor, &30_000_000);
+}
+
+// Test 86: A time-locked campaign can't be handed to signers, whose withdrawal
+// proposals would skip the timelock
+#[test]
+#[should_panic(
+    expected = "Multisig can't be combined with vesting, timelocks, loans or revenue sharing"
+)]
+fn test_multisig_with_timelock() {
+    let env = Env::default();
+    let (client, owner, _) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            withdrawal_delay: 86400,
+            ..Default::default()
+        },
+    );
+
+    let signers = soroban_sdk::vec![&env, owner.clone(), Address::generate(&env)];
+    client.set_signers(&owner, &signers, &2);
+}
+
+// Test 87: Signers draw the raffle through a proposal
+#[test]
+fn test_multisig_draw_winners() {
+    let env = Env::default();
+    let (client, owner, _) = setup_raffle_campaign(&env);
+
+    let signer = Address::generate(&env);
+    client.set_signers(&owner, &soroban_sdk::vec![&env, signer.clone()], &1);
+    let id = client.propose_action(&signer, &ProposalAction::DrawWinners(2, false));
+    client.execute_action(&signer, &id);
+
+    assert_eq!(client.get_raffle_winners().len(), 2);
+}
+
+// Test 88: A share of an oversubscribed sale too small to buy a single token
+// unit still gets its refund
+#[test]
+fn test_claim_refund_without_tokens() {
+    let env = Env::default();
+    let (client, _, token_admin, project_token) = setup_presale(&env, 100_000_000, 100_000_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 1_000_000);
+    donate_from_new_donor(&env, &client, &token_admin, 500_000_000_000_000);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    assert_eq!(client.get_claimable_tokens(&donor), 0);
+    assert_eq!(client.get_allocation(&donor), (1, 999_999));
+    assert_eq!(client.claim_tokens(&donor), 0);
+    assert_eq!(token.balance(&donor), 999_999);
+    assert_eq!(project_token.balance(&donor), 0);
+}
+
+// Test 89: Withdrawing a donation only gives back the matching it drew
+#[test]
+fn test_unpledge_unmatched_donation() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    // Match 1:1 up to 1 XLM, all of it taken by the first donor
+    let sponsor = Address::generate(&env);
+    token_admin.mint(&sponsor, &10_000_000);
+    let pool_id = client.add_matching_pool(&sponsor, &10_000_000, &10_000, &10_000_000);
+    let donor_a = donate_from_new_donor(&env, &client, &token_admin, 10_000_000);
+    let donor_b = donate_from_new_donor(&env, &client, &token_admin, 20_000_000);
+    assert_eq!(client.get_total_raised(), 40_000_000);
+
+    client.unpledge(&donor_b, &20_000_000);
+    assert_eq!(client.get_total_raised(), 20_000_000);
+    assert_eq!(client.get_matching_pool(&pool_id).matched, 10_000_000);
+
+    client.unpledge(&donor_a, &5_000_000);
+    assert_eq!(client.get_total_raised(), 10_000_000);
+    assert_eq!(client.get_total_matched(), 5_000_000);
+}
+
+// Test 90: A campaign can't be initialized a second time to take it over
+#[test]
+#[should_panic(expected = "Campaign already initialized")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    client.initialize(
+        &Address::generate(&env),
+        &100_000_000,
+        &(env.ledger().timestamp() + 86400),
+        &token_admin.address,
+        &soroban_sdk::String::from_str(&env, "Hijacked"),
+        &soroban_sdk::String::from_str(&env, "Hijacked"),
+        &soroban_sdk::String::from_str(&env, "https://example.com/image.jpg"),
+        &1_000_000,
+        &create_platform(&env),
+        &CampaignFeatures::default(),
+    );
+}
+
+// Test 91: Matching stays in place while a cool-off withdrawal can still reopen
+// the campaign, so later donations are matched from funds the pool still holds
+#[test]
+fn test_return_matching_before_deadline() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            cool_off: 86400,
+            ..Default::default()
+        },
+    );
+    let token = token::Client::new(&env, &token_admin.address);
+
+    // Match 1:1 up to 5 XLM
+    let sponsor = Address::generate(&env);
+    token_admin.mint(&sponsor, &50_000_000);
+    let pool_id = client.add_matching_pool(&sponsor, &50_000_000, &10_000, &50_000_000);
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 60_000_000);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Successful as u32
+    );
+    assert!(client.try_return_matching(&pool_id).is_err());
+
+    client.unpledge(&donor, &20_000_000);
+    assert_eq!(client.get_campaign_status(), CampaignStatus::Active as u32);
+    donate_from_new_donor(&env, &client, &token_admin, 30_000_000);
+
+    // Everything credited is backed by the contract's balance
+    let pool = client.get_matching_pool(&pool_id);
+    assert_eq!(client.get_total_raised(), 120_000_000);
+    assert_eq!(
+        token.balance(&client.address),
+        client.get_total_raised() + pool.deposited - pool.matched
+    );
+}
+
+// Test 92: Dispute refunds come out of the donations, never the matching
+#[test]
+fn test_dispute_refund_with_matching() {
+    let env = Env::default();
+    let arbiter = Address::generate(&env);
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            arbiter: Some(arbiter.clone()),
+            challenge_window: 3 * 86400,
+            ..Default::default()
+        },
+    );
+    let token = token::Client::new(&env, &token_admin.address);
+
+    // Match 1:1 up to 5 XLM
+    let sponsor = Address::generate(&env);
+    token_admin.mint(&sponsor, &50_000_000);
+    client.add_matching_pool(&sponsor, &50_000_000, &10_000, &50_000_000);
+    let donor_a = donate_from_new_donor(&env, &client, &token_admin, 30_000_000);
+    let donor_b = donate_from_new_donor(&env, &client, &token_admin, 20_000_000);
+    assert_eq!(client.get_total_raised(), 100_000_000);
+
+    client.open_dispute(&donor_a, &soroban_sdk::String::from_str(&env, "ipfs://x"));
+    client.resolve_dispute(&arbiter, &10_000);
+
+    assert_eq!(client.claim_dispute_refund(&donor_a), 30_000_000);
+    assert_eq!(client.claim_dispute_refund(&donor_b), 20_000_000);
+    assert_eq!(client.get_donation(&donor_a), 0);
+    assert_eq!(client.get_donation(&donor_b), 0);
+
+    client.withdraw(&owner);
+    assert_eq!(token.balance(&owner), 50_000_000);
+}
+
+// Test 93: Disputes the arbiter never rules on refund every donation after the
+// timeout
+#[test]
+fn test_dispute_timeout_refunds_donors() {
+    let env = Env::default();
+    let (client, owner, token_admin, _, donor_a, donor_b) = setup_arbitrated_campaign(&env);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    client.open_dispute(&donor_b, &soroban_sdk::String::from_str(&env, "ipfs://x"));
+    env.ledger().with_mut(|li| {
+        li.timestamp += DISPUTE_TIMEOUT;
+    });
+    assert!(client.try_expire_dispute().is_err());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 1;
+    });
+    assert_eq!(client.expire_dispute(), 100_000_000);
+    assert_eq!(client.claim_dispute_refund(&donor_a), 60_000_000);
+    assert_eq!(client.claim_dispute_refund(&donor_b), 40_000_000);
+    assert_eq!(token.balance(&donor_a), 60_000_000);
+
+    client.withdraw(&owner);
+    assert_eq!(token.balance(&owner), 0);
+}
+
+// Test 94: The owner can't arbitrate disputes about their own campaign
+#[test]
+#[should_panic(expected = "Arbiter must not be the campaign owner")]
+fn test_arbiter_is_owner() {
+    let env = Env::default();
+    let owner = Address::generate(&env);
+    setup_campaign_as(
+        &env,
+        &owner,
+        100_000_000,
+        CampaignFeatures {
+            arbiter: Some(owner.clone()),
+            challenge_window: 3 * 86400,
+            ..Default::default()
+        },
+    );
+}
+
+// Test 95: A presale campaign can't be handed to signers, who couldn't reclaim
+// the unsold tokens
+#[test]
+#[should_panic(expected = "Multisig can't be combined with a presale")]
+fn test_multisig_with_presale() {
+    let env = Env::default();
+    let (client, owner, _, _) = setup_presale(&env, 100_000_000, 0);
+
+    let signers = soroban_sdk::vec![&env, owner.clone(), Address::generate(&env)];
+    client.set_signers(&owner, &signers, &2);
+}
+
+// Test 96: Vested payouts would skip the withdrawal delay, so a campaign can't
+// have both
+#[test]
+#[should_panic(expected = "Withdrawal delays can't be combined with vesting")]
+fn test_timelock_with_vesting() {
+    let env = Env::default();
+    setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            vesting: VestingSchedule {
+                cliff: 0,
+                duration: 86400,
+            },
+            withdrawal_delay: 86400,
+            ..Default::default()
+        },
+    );
+}
+
+// Test 97: A veto blocks new withdrawal requests until the admin lifts it
+#[test]
+fn test_request_after_veto() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            withdrawal_delay: 2 * 86400,
+            ..Default::default()
+        },
+    );
+    let (_, admin) = setup_platform(&env, &client);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+
+    client.request_withdrawal(&owner);
+    client.veto_withdrawal(
+        &admin,
+        &soroban_sdk::String::from_str(&env, "Fraud reported"),
+    );
+    assert!(client.is_withdrawal_vetoed());
+    assert!(client.try_request_withdrawal(&owner).is_err());
+
+    client.lift_withdrawal_veto(&admin);
+    let release_at = client.request_withdrawal(&owner);
+    assert_eq!(release_at, env.ledger().timestamp() + 2 * 86400);
+}
+
+// Test 98: A cool-off too long to add to the donation time never ends rather
+// than overflowing
+#[test]
+fn test_unbounded_cool_off() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            cool_off: u64::MAX,
+            ..Default::default()
+        },
+    );
+
+    let donor = donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+    assert!(client.try_withdraw(&owner).is_err());
+
+    client.unpledge(&donor, &10_000_000);
+    assert_eq!(client.get_total_raised(), 90_000_000);
+}
+
+// Test 99: Only the donor can read their unmasked donation records
+#[test]
+fn test_donations_by_donor_requires_auth() {
+    let env = Env::default();
+    let (client, _, token_admin) = setup_campaign(&env, 100_000_000);
+
+    let donor = Address::generate(&env);
+    token_admin.mint(&donor, &10_000_000);
+    client.donate_with_note(&donor, &10_000_000, &None, &None, &true);
+
+    env.set_auths(&[]);
+    assert!(client.try_get_donations_by_donor(&donor, &0, &10).is_err());
+
+    let records = client
+        .mock_all_auths()
+        .get_donations_by_donor(&donor, &0, &10);
+    assert!(records.get(0).unwrap().anonymous);
+}
+
+// Test 100: A defaulted loan still takes matching, which goes to the owner
+#[test]
+fn test_defaulted_loan_receives_match() {
+    let env = Env::default();
+    let (client, owner, token_admin) = setup_campaign_with(
+        &env,
+        100_000_000,
+        CampaignFeatures {
+            loan: LoanTerms {
+                interest_bps: 0,
+                installments: 4,
+                interval: 86400,
+            },
+            ..Default::default()
+        },
+    );
+    let token = token::Client::new(&env, &token_admin.address);
+
+    donate_from_new_donor(&env, &client, &token_admin, 100_000_000);
+    client.withdraw(&owner);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400;
+    });
+    assert_eq!(client.check_default(), CampaignStatus::Defaulted as u32);
+
+    let funder = Address::generate(&env);
+    token_admin.mint(&funder, &5_000_000);
+    client.receive_match(&funder, &5_000_000);
+    assert_eq!(token.balance(&owner), 105_000_000);
+    assert_eq!(
+        client.get_campaign_status(),
+        CampaignStatus::Defaulted as u32
+    );
+}
@@ -0,0 +1,174 @@
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, symbol_short, Address, Env, String, Symbol,
+};
+
+use crate::{
+    ensure_withdrawable, pause, release_funds, require_owner, vesting, CrowdfundingContract,
+    CrowdfundingContractArgs, CrowdfundingContractClient,
+};
+
+// Storage keys for the withdrawal timelock
+const WITHDRAWAL_DELAY: Symbol = symbol_short!("wd_delay");
+const PENDING_WITHDRAWAL: Symbol = symbol_short!("wd_pend");
+const VETOED: Symbol = symbol_short!("wd_veto");
+
+// An announced withdrawal waiting out its timelock
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingWithdrawal {
+    pub requested_at: u64,
+    pub release_at: u64, // Earliest time `execute_withdrawal` can run
+}
+
+// Emitted when the owner announces a withdrawal
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRequested {
+    #[topic]
+    pub owner: Address,
+    pub release_at: u64,
+}
+
+// Emitted when the platform admin blocks an announced withdrawal
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalVetoed {
+    #[topic]
+    pub admin: Address,
+    pub reason: String,
+}
+
+// Emitted when an announced withdrawal is paid out
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalExecuted {
+    #[topic]
+    pub owner: Address,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Announce a withdrawal. The platform admin can veto it until it is released
+    pub fn request_withdrawal(env: Env, owner: Address) -> u64 {
+        require_owner(&env, &owner);
+
+        if !is_enabled(&env) {
+            panic!("Campaign has no withdrawal delay");
+        }
+        if env.storage().instance().has(&PENDING_WITHDRAWAL) {
+            panic!("Withdrawal already requested");
+        }
+        if Self::is_withdrawal_vetoed(env.clone()) {
+            panic!("Withdrawals are vetoed");
+        }
+
+        // Only announce what could be paid out right now
+        ensure_withdrawable(&env);
+
+        let requested_at = env.ledger().timestamp();
+        let release_at = requested_at + Self::get_withdrawal_delay(env.clone());
+        env.storage().instance().set(
+            &PENDING_WITHDRAWAL,
+            &PendingWithdrawal {
+                requested_at,
+                release_at,
+            },
+        );
+
+        WithdrawalRequested { owner, release_at }.publish(&env);
+
+        release_at
+    }
+
+    // Pay out an announced withdrawal once its timelock has passed
+    pub fn execute_withdrawal(env: Env, owner: Address) {
+        require_owner(&env, &owner);
+
+        if !env.storage().instance().has(&PENDING_WITHDRAWAL) {
+            panic!("No withdrawal requested");
+        }
+
+        // Checks the timelock has passed
+        release_funds(&env, &owner);
+
+        WithdrawalExecuted { owner }.publish(&env);
+    }
+
+    // Block an announced withdrawal, e.g. when fraud is reported. No new
+    // withdrawal can be requested until the platform admin lifts the veto
+    pub fn veto_withdrawal(env: Env, admin: Address, reason: String) {
+        require_platform_admin(&env, &admin);
+
+        if !env.storage().instance().has(&PENDING_WITHDRAWAL) {
+            panic!("No withdrawal requested");
+        }
+        env.storage().instance().remove(&PENDING_WITHDRAWAL);
+        env.storage().instance().set(&VETOED, &true);
+
+        WithdrawalVetoed { admin, reason }.publish(&env);
+    }
+
+    // Let the owner request withdrawals again once a veto has been cleared up
+    pub fn lift_withdrawal_veto(env: Env, admin: Address) {
+        require_platform_admin(&env, &admin);
+
+        env.storage().instance().remove(&VETOED);
+    }
+
+    // Check whether the platform admin has blocked withdrawal requests
+    pub fn is_withdrawal_vetoed(env: Env) -> bool {
+        env.storage().instance().has(&VETOED)
+    }
+
+    // Get the announced withdrawal, if there is one
+    pub fn get_pending_withdrawal(env: Env) -> Option<PendingWithdrawal> {
+        env.storage().instance().get(&PENDING_WITHDRAWAL)
+    }
+
+    // Get the withdrawal timelock in seconds (0 if withdrawals are immediate)
+    pub fn get_withdrawal_delay(env: Env) -> u64 {
+        env.storage().instance().get(&WITHDRAWAL_DELAY).unwrap_or(0)
+    }
+}
+
+// Make withdrawals two-phase: announced with `request_withdrawal`, paid out
+// with `execute_withdrawal` after `delay` seconds. A zero delay pays out at once
+pub(crate) fn configure(env: &Env, delay: u64) {
+    if delay == 0 {
+        return;
+    }
+    // Vested payouts don't go through a withdrawal request
+    if vesting::is_enabled(env) {
+        panic!("Withdrawal delays can't be combined with vesting");
+    }
+    env.storage().instance().set(&WITHDRAWAL_DELAY, &delay);
+}
+
+// Consume the announced withdrawal being paid out. Every payout goes through
+// here, so time-locked funds never leave before the delay has passed
+pub(crate) fn take_matured_request(env: &Env) {
+    if !is_enabled(env) {
+        return;
+    }
+    let pending: PendingWithdrawal = match env.storage().instance().get(&PENDING_WITHDRAWAL) {
+        Some(pending) => pending,
+        None => panic!("Withdrawal must be requested first"),
+    };
+    if env.ledger().timestamp() < pending.release_at {
+        panic!("Withdrawal is still time-locked");
+    }
+    env.storage().instance().remove(&PENDING_WITHDRAWAL);
+}
+
+fn require_platform_admin(env: &Env, admin: &Address) {
+    // Verify the platform admin is authorized
+    admin.require_auth();
+    if *admin != pause::platform_admin(env) {
+        panic!("Only platform admin can perform this action");
+    }
+}
+
+// Whether withdrawals have to be announced first
+pub(crate) fn is_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&WITHDRAWAL_DELAY)
+}
@@ -0,0 +1,122 @@
+use soroban_sdk::{contractevent, contractimpl, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    matching, refresh_status, refunds, storage, storage::DataKey, xlm_token, CampaignStatus,
+    CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient, CAMPAIGN_DEADLINE,
+    CAMPAIGN_GOAL, CAMPAIGN_STATUS, TOTAL_RAISED,
+};
+
+// Storage keys for donation withdrawal
+const COOL_OFF: Symbol = symbol_short!("cool_off");
+const LAST_DONATION: Symbol = symbol_short!("last_don");
+
+// Emitted when a donor takes back some or all of their donation
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DonationWithdrawn {
+    #[topic]
+    pub donor: Address,
+    pub amount: i128,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Take back `amount` of a donation. Allowed while the campaign is running
+    // and under its goal, or within the cool-off period after the donor's latest
+    // donation. The funds go to the donor's refund destination
+    pub fn unpledge(env: Env, donor: Address, amount: i128) {
+        // Verify the donor is authorized
+        donor.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        let donation = storage::donation_of(&env, &donor);
+        if amount > donation {
+            panic!("Amount exceeds donation");
+        }
+        if refunds::is_refunded(&env, &donor) {
+            panic!("Donation already refunded");
+        }
+
+        let status = refresh_status(&env);
+        if status != CampaignStatus::Active as u32 && status != CampaignStatus::Successful as u32 {
+            panic!("Campaign is not active");
+        }
+
+        let now = env.ledger().timestamp();
+        let deadline: u64 = env.storage().instance().get(&CAMPAIGN_DEADLINE).unwrap();
+        let open = status == CampaignStatus::Active as u32 && now <= deadline;
+        let last_donation: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::LastDonation(donor.clone()))
+            .unwrap_or(0);
+        if !open && !in_cool_off(&env, last_donation) {
+            panic!("Donation can no longer be withdrawn");
+        }
+
+        // Sponsors only match what stays in the campaign
+        let unmatched = matching::unapply_matching(&env, &donor, amount, donation);
+
+        storage::set_donation(&env, &donor, donation - amount);
+        let total: i128 = env.storage().instance().get(&TOTAL_RAISED).unwrap();
+        let total = total - amount - unmatched;
+        env.storage().instance().set(&TOTAL_RAISED, &total);
+
+        // Dropping back under the goal reopens the campaign. After the deadline
+        // it then settles as failed
+        let goal: i128 = env.storage().instance().get(&CAMPAIGN_GOAL).unwrap();
+        if status == CampaignStatus::Successful as u32 && total < goal {
+            env.storage()
+                .instance()
+                .set(&CAMPAIGN_STATUS, &(CampaignStatus::Active as u32));
+            refresh_status(&env);
+        }
+
+        let destination = storage::refund_destination(&env, &donor);
+        xlm_token(&env).transfer(&env.current_contract_address(), &destination, &amount);
+
+        DonationWithdrawn { donor, amount }.publish(&env);
+    }
+
+    // Get the cool-off period after each donation, in seconds
+    pub fn get_cool_off(env: Env) -> u64 {
+        cool_off(&env)
+    }
+}
+
+// Let donors take a donation back for `period` seconds after making it, even
+// once the goal is reached. Zero leaves no cool-off
+pub(crate) fn configure(env: &Env, period: u64) {
+    if period > 0 {
+        env.storage().instance().set(&COOL_OFF, &period);
+    }
+}
+
+// Remember when the donor last donated, to time their cool-off
+pub(crate) fn record_donation_time(env: &Env, donor: &Address) {
+    let now = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastDonation(donor.clone()), &now);
+    env.storage().instance().set(&LAST_DONATION, &now);
+}
+
+// Panic while any donation can still be taken back under the cool-off
+pub(crate) fn ensure_cool_off_over(env: &Env) {
+    let last_donation: u64 = env.storage().instance().get(&LAST_DONATION).unwrap_or(0);
+    if in_cool_off(env, last_donation) {
+        panic!("Donations are still in their cool-off period");
+    }
+}
+
+// Whether a donation made at `donated_at` can still be taken back
+fn in_cool_off(env: &Env, donated_at: u64) -> bool {
+    let period = cool_off(env);
+    period > 0 && env.ledger().timestamp() <= donated_at.saturating_add(period)
+}
+
+fn cool_off(env: &Env) -> u64 {
+    env.storage().instance().get(&COOL_OFF).unwrap_or(0)
+}
@@ -0,0 +1,66 @@
+use soroban_sdk::{contractevent, contractimpl, Address, BytesN, Env};
+
+use crate::{
+    pause,
+    storage::{self, CURRENT_SCHEMA, SCHEMA_VERSION},
+    CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient,
+};
+
+// Emitted when the platform admin swaps in new contract code
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+}
+
+// Emitted when stored data is converted to a newer layout
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StorageMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Replace the contract code in place, keeping its address, storage and funds.
+    // Call `migrate` afterwards if the new code uses a newer storage layout
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        // Verify the platform admin is authorized
+        admin.require_auth();
+        if admin != pause::platform_admin(&env) {
+            panic!("Only platform admin can perform this action");
+        }
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        ContractUpgraded { new_wasm_hash }.publish(&env);
+    }
+
+    // Convert data written by older versions to the current layout.
+    // Anyone can call it: it only reshapes storage and never moves funds
+    pub fn migrate(env: Env) {
+        let from_version = storage::schema_version(&env);
+        if from_version >= CURRENT_SCHEMA {
+            panic!("Storage already migrated");
+        }
+
+        // Every upgradeable campaign was created on the current layout, so there
+        // is nothing to convert yet. Later layout changes convert here, oldest first
+        env.storage()
+            .instance()
+            .set(&SCHEMA_VERSION, &CURRENT_SCHEMA);
+
+        StorageMigrated {
+            from_version,
+            to_version: CURRENT_SCHEMA,
+        }
+        .publish(&env);
+    }
+
+    // Get the storage layout version
+    pub fn get_schema_version(env: Env) -> u32 {
+        storage::schema_version(&env)
+    }
+}
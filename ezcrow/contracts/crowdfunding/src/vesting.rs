@@ -0,0 +1,158 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::{
+    arbitration, pause, presale, refresh_status, require_owner, unpledge, xlm_token,
+    CampaignStatus, CrowdfundingContract, CrowdfundingContractArgs, CrowdfundingContractClient,
+    Operation, CAMPAIGN_STATUS, TOTAL_RAISED, WITHDRAWN,
+};
+
+// Storage keys for the vesting schedule
+const VESTING: Symbol = symbol_short!("vesting");
+const SUCCESS_AT: Symbol = symbol_short!("succ_at");
+const VESTED_CLAIMED: Symbol = symbol_short!("vest_clm");
+
+// Funds unlock linearly from the moment the campaign succeeds: nothing before
+// `cliff`, everything after `duration` (both in seconds)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub cliff: u64,
+    pub duration: u64,
+}
+
+// Where the owner's payout stands
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingStatus {
+    pub cliff: u64,
+    pub duration: u64,
+    pub start: Option<u64>, // When the campaign succeeded, if it has
+    pub total: i128,        // Everything the owner will receive
+    pub vested: i128,       // Unlocked so far
+    pub claimed: i128,      // Already paid out
+    pub claimable: i128,    // Unlocked but not yet paid out
+}
+
+#[contractimpl]
+impl CrowdfundingContract {
+    // Pay the owner everything that has vested but hasn't been claimed yet.
+    // Returns the amount paid
+    pub fn claim_vested(env: Env, owner: Address) -> i128 {
+        require_owner(&env, &owner);
+
+        // Check withdrawals haven't been paused by the platform
+        pause::ensure_not_paused(&env, Operation::Withdraw);
+
+        if !is_enabled(&env) {
+            panic!("Campaign has no vesting schedule");
+        }
+        match refresh_status(&env) {
+            s if s == CampaignStatus::Withdrawn as u32 => panic!("Funds already withdrawn"),
+            s if s != CampaignStatus::Successful as u32 => panic!("Campaign is not successful"),
+            _ => {}
+        }
+
+        // Donors can still change their mind during the cool-off
+        unpledge::ensure_cool_off_over(&env);
+
+        // Donors can still challenge the campaign before the arbiter
+        arbitration::ensure_not_frozen(&env);
+
+        let status = Self::get_vesting_status(env.clone());
+        if status.claimable <= 0 {
+            panic!("Nothing to claim yet");
+        }
+
+        env.storage()
+            .instance()
+            .set(&VESTED_CLAIMED, &(status.claimed + status.claimable));
+        if status.claimed + status.claimable == status.total {
+            env.storage().instance().set(&WITHDRAWN, &true);
+            env.storage()
+                .instance()
+                .set(&CAMPAIGN_STATUS, &(CampaignStatus::Withdrawn as u32));
+        }
+
+        xlm_token(&env).transfer(&env.current_contract_address(), &owner, &status.claimable);
+
+        status.claimable
+    }
+
+    // Get the vesting schedule and how much has vested and been claimed
+    pub fn get_vesting_status(env: Env) -> VestingStatus {
+        let schedule: VestingSchedule = match env.storage().instance().get(&VESTING) {
+            Some(schedule) => schedule,
+            None => panic!("Campaign has no vesting schedule"),
+        };
+        let claimed: i128 = env.storage().instance().get(&VESTED_CLAIMED).unwrap_or(0);
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get::<_, i128>(&TOTAL_RAISED)
+            .unwrap()
+            - presale::oversubscribed(&env);
+
+        // Nothing vests until the campaign has succeeded
+        let status: u32 = env.storage().instance().get(&CAMPAIGN_STATUS).unwrap();
+        let start: Option<u64> = if status == CampaignStatus::Successful as u32
+            || status == CampaignStatus::Withdrawn as u32
+        {
+            env.storage().instance().get(&SUCCESS_AT)
+        } else {
+            None
+        };
+
+        let vested = match start {
+            Some(start) => {
+                let elapsed = env.ledger().timestamp().saturating_sub(start);
+                if elapsed < schedule.cliff {
+                    0
+                } else if elapsed >= schedule.duration {
+                    total
+                } else {
+                    total * elapsed as i128 / schedule.duration as i128
+                }
+            }
+            None => 0,
+        };
+
+        VestingStatus {
+            cliff: schedule.cliff,
+            duration: schedule.duration,
+            start,
+            total,
+            vested,
+            claimed,
+            claimable: vested - claimed,
+        }
+    }
+}
+
+// Release funds to the owner over the schedule instead of all at once. An
+// all-zero schedule leaves vesting off
+pub(crate) fn configure(env: &Env, schedule: &VestingSchedule) {
+    if *schedule == VestingSchedule::default() {
+        return;
+    }
+    if schedule.duration == 0 || schedule.cliff > schedule.duration {
+        panic!("Invalid vesting schedule");
+    }
+    env.storage().instance().set(&VESTING, schedule);
+}
+
+// Whether the owner is paid through vesting rather than `withdraw`
+pub(crate) fn is_enabled(env: &Env) -> bool {
+    env.storage().instance().has(&VESTING)
+}
+
+// When the campaign reached its goal, if it has
+pub(crate) fn success_at(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&SUCCESS_AT)
+}
+
+// Remember when the campaign reached its goal, which starts the vesting
+pub(crate) fn record_success(env: &Env) {
+    env.storage()
+        .instance()
+        .set(&SUCCESS_AT, &env.ledger().timestamp());
+}
@@ -0,0 +1,298 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contractevent, contractimpl, contracttype, symbol_short, token, Address, Env, Symbol,
+};
+
+// Storage keys for our contract data
+const BUYER: Symbol = symbol_short!("buyer");
+const SELLER: Symbol = symbol_short!("seller");
+const ARBITER: Symbol = symbol_short!("arbiter");
+const TOKEN_ADDRESS: Symbol = symbol_short!("token");
+const AMOUNT: Symbol = symbol_short!("amount");
+const DEADLINE: Symbol = symbol_short!("deadline");
+const ESCROW_STATUS: Symbol = symbol_short!("status");
+const DISPUTED_AT: Symbol = symbol_short!("disp_at");
+const IS_ALREADY_INIT: Symbol = symbol_short!("is_init");
+
+// The arbiter gets this long to settle a dispute before the buyer can take the
+// funds back anyway
+pub const DISPUTE_TIMEOUT: u64 = 30 * 24 * 60 * 60;
+
+// Escrow status enum
+#[derive(Clone, Copy, PartialEq)]
+pub enum EscrowStatus {
+    Created = 0,  // Waiting for the buyer to fund
+    Funded = 1,   // Funds held until release, timeout or dispute
+    Released = 2, // Paid to the seller
+    Refunded = 3, // Paid back to the buyer
+    Disputed = 4, // Waiting for the arbiter, up to the dispute timeout
+}
+
+// Everything about the deal in one view
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowInfo {
+    pub buyer: Address,
+    pub seller: Address,
+    pub arbiter: Option<Address>,
+    pub token: Address,
+    pub amount: i128,
+    pub deadline: u64,
+    pub status: u32,
+}
+
+// Emitted when the buyer deposits the funds
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowFunded {
+    #[topic]
+    pub buyer: Address,
+    pub amount: i128,
+}
+
+// Emitted when the funds go to the seller
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReleased {
+    #[topic]
+    pub seller: Address,
+    pub amount: i128,
+}
+
+// Emitted when the funds go back to the buyer
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowRefunded {
+    #[topic]
+    pub buyer: Address,
+    pub amount: i128,
+}
+
+// Emitted when either party hands the deal to the arbiter
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowDisputed {
+    #[topic]
+    pub raised_by: Address,
+}
+
+// Two-party escrow: the buyer's funds are held until the buyer releases them,
+// the deadline passes without release, or the arbiter settles a dispute
+#[contract]
+pub struct EscrowContract;
+
+#[contractimpl]
+impl EscrowContract {
+    // Set up the deal
+    pub fn initialize(
+        env: Env,
+        buyer: Address,           // Who pays
+        seller: Address,          // Who gets paid on release
+        arbiter: Option<Address>, // Who settles disputes (no disputes without one)
+        token: Address,           // Token the deal is paid in
+        amount: i128,             // Amount held in escrow
+        deadline: u64,            // Unix timestamp after which the buyer can take the funds back
+    ) {
+        // Verify the buyer is who they claim to be
+        buyer.require_auth();
+
+        if env.storage().instance().has(&IS_ALREADY_INIT) {
+            panic!("Escrow already initialized");
+        }
+        if buyer == seller {
+            panic!("Buyer and seller must differ");
+        }
+        if arbiter.as_ref() == Some(&buyer) || arbiter.as_ref() == Some(&seller) {
+            panic!("Arbiter must be neither the buyer nor the seller");
+        }
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        if deadline <= env.ledger().timestamp() {
+            panic!("Deadline must be in the future");
+        }
+
+        env.storage().instance().set(&BUYER, &buyer);
+        env.storage().instance().set(&SELLER, &seller);
+        if let Some(arbiter) = arbiter {
+            env.storage().instance().set(&ARBITER, &arbiter);
+        }
+        env.storage().instance().set(&TOKEN_ADDRESS, &token);
+        env.storage().instance().set(&AMOUNT, &amount);
+        env.storage().instance().set(&DEADLINE, &deadline);
+        env.storage()
+            .instance()
+            .set(&ESCROW_STATUS, &(EscrowStatus::Created as u32));
+        env.storage().instance().set(&IS_ALREADY_INIT, &true);
+    }
+
+    // Deposit the agreed amount
+    pub fn fund(env: Env, buyer: Address) {
+        require_party(&env, &buyer, &BUYER, "Only the buyer can fund");
+        require_status(&env, EscrowStatus::Created);
+
+        let deadline: u64 = env.storage().instance().get(&DEADLINE).unwrap();
+        if env.ledger().timestamp() > deadline {
+            panic!("Escrow has expired");
+        }
+
+        // Transfer the tokens from the buyer to this contract
+        let amount: i128 = env.storage().instance().get(&AMOUNT).unwrap();
+        let contract_address = env.current_contract_address();
+        escrow_token(&env).transfer(&buyer, &contract_address, &amount);
+
+        set_status(&env, EscrowStatus::Funded);
+
+        EscrowFunded { buyer, amount }.publish(&env);
+    }
+
+    // Pay the seller once the buyer is satisfied
+    pub fn release(env: Env, buyer: Address) {
+        require_party(&env, &buyer, &BUYER, "Only the buyer can release");
+        require_status(&env, EscrowStatus::Funded);
+
+        pay_seller(&env);
+    }
+
+    // Take the funds back when the deadline passed without a release, or the
+    // arbiter left a dispute unsettled past the dispute timeout. Anyone can
+    // call it; the funds always go to the buyer
+    pub fn refund_on_timeout(env: Env) {
+        let now = env.ledger().timestamp();
+        let status: u32 = env.storage().instance().get(&ESCROW_STATUS).unwrap();
+        if status == EscrowStatus::Disputed as u32 {
+            let disputed_at: u64 = env.storage().instance().get(&DISPUTED_AT).unwrap();
+            if now <= disputed_at + DISPUTE_TIMEOUT {
+                panic!("Dispute has not timed out");
+            }
+        } else {
+            require_status(&env, EscrowStatus::Funded);
+
+            let deadline: u64 = env.storage().instance().get(&DEADLINE).unwrap();
+            if now <= deadline {
+                panic!("Deadline has not passed");
+            }
+        }
+
+        refund_buyer(&env);
+    }
+
+    // Hand the deal to the arbiter. Either party can do it while the funds are held
+    pub fn dispute(env: Env, caller: Address) {
+        caller.require_auth();
+
+        let buyer: Address = env.storage().instance().get(&BUYER).unwrap();
+        let seller: Address = env.storage().instance().get(&SELLER).unwrap();
+        if caller != buyer && caller != seller {
+            panic!("Only the buyer or seller can dispute");
+        }
+        if !env.storage().instance().has(&ARBITER) {
+            panic!("Escrow has no arbiter");
+        }
+        require_status(&env, EscrowStatus::Funded);
+
+        set_status(&env, EscrowStatus::Disputed);
+        env.storage()
+            .instance()
+            .set(&DISPUTED_AT, &env.ledger().timestamp());
+
+        EscrowDisputed { raised_by: caller }.publish(&env);
+    }
+
+    // Settle a dispute by paying the seller or refunding the buyer
+    pub fn resolve(env: Env, arbiter: Address, release_to_seller: bool) {
+        require_party(
+            &env,
+            &arbiter,
+            &ARBITER,
+            "Only the arbiter can resolve disputes",
+        );
+        require_status(&env, EscrowStatus::Disputed);
+
+        if release_to_seller {
+            pay_seller(&env);
+        } else {
+            refund_buyer(&env);
+        }
+    }
+
+    // Get the deal terms and its status
+    pub fn get_escrow(env: Env) -> EscrowInfo {
+        if !env.storage().instance().has(&IS_ALREADY_INIT) {
+            panic!("Escrow not initialized");
+        }
+
+        EscrowInfo {
+            buyer: env.storage().instance().get(&BUYER).unwrap(),
+            seller: env.storage().instance().get(&SELLER).unwrap(),
+            arbiter: env.storage().instance().get(&ARBITER),
+            token: env.storage().instance().get(&TOKEN_ADDRESS).unwrap(),
+            amount: env.storage().instance().get(&AMOUNT).unwrap(),
+            deadline: env.storage().instance().get(&DEADLINE).unwrap(),
+            status: Self::get_status(env),
+        }
+    }
+
+    // Get the EscrowStatus as u32
+    pub fn get_status(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&ESCROW_STATUS)
+            .unwrap_or(EscrowStatus::Created as u32)
+    }
+}
+
+// Check that `caller` is the address stored under `role` and authorized the call
+fn require_party(env: &Env, caller: &Address, role: &Symbol, message: &str) {
+    caller.require_auth();
+
+    let expected: Option<Address> = env.storage().instance().get(role);
+    if expected.as_ref() != Some(caller) {
+        panic!("{}", message);
+    }
+}
+
+fn require_status(env: &Env, expected: EscrowStatus) {
+    let status: u32 = env.storage().instance().get(&ESCROW_STATUS).unwrap();
+    if status != expected as u32 {
+        match expected {
+            EscrowStatus::Created => panic!("Escrow already funded"),
+            EscrowStatus::Disputed => panic!("Escrow is not disputed"),
+            _ => panic!("Escrow is not funded"),
+        }
+    }
+}
+
+fn set_status(env: &Env, status: EscrowStatus) {
+    env.storage()
+        .instance()
+        .set(&ESCROW_STATUS, &(status as u32));
+}
+
+fn escrow_token(env: &Env) -> token::Client<'_> {
+    let token_address: Address = env.storage().instance().get(&TOKEN_ADDRESS).unwrap();
+    token::Client::new(env, &token_address)
+}
+
+fn pay_seller(env: &Env) {
+    let seller: Address = env.storage().instance().get(&SELLER).unwrap();
+    let amount: i128 = env.storage().instance().get(&AMOUNT).unwrap();
+
+    set_status(env, EscrowStatus::Released);
+    escrow_token(env).transfer(&env.current_contract_address(), &seller, &amount);
+
+    EscrowReleased { seller, amount }.publish(env);
+}
+
+fn refund_buyer(env: &Env) {
+    let buyer: Address = env.storage().instance().get(&BUYER).unwrap();
+    let amount: i128 = env.storage().instance().get(&AMOUNT).unwrap();
+
+    set_status(env, EscrowStatus::Refunded);
+    escrow_token(env).transfer(&env.current_contract_address(), &buyer, &amount);
+
+    EscrowRefunded { buyer, amount }.publish(env);
+}
+
+#[cfg(test)]
+mod test;
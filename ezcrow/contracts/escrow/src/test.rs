@@ -0,0 +1,214 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::Address as _, testutils::Events, testutils::Ledger, token, Address, Env, IntoVal,
+};
+
+// Helper: an escrow for 50 XLM due in a day, with the buyer already holding the funds
+fn setup_escrow<'a>(
+    env: &Env,
+    with_arbiter: bool,
+) -> (
+    EscrowContractClient<'a>,
+    Address,
+    Address,
+    Address,
+    token::Client<'a>,
+) {
+    let contract_id = env.register(EscrowContract, ());
+    let client = EscrowContractClient::new(env, &contract_id);
+
+    let buyer = Address::generate(env);
+    let seller = Address::generate(env);
+    let arbiter = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    env.mock_all_auths();
+
+    token::StellarAssetClient::new(env, &token_address).mint(&buyer, &500_000_000);
+    client.initialize(
+        &buyer,
+        &seller,
+        &if with_arbiter {
+            Some(arbiter.clone())
+        } else {
+            None
+        },
+        &token_address,
+        &500_000_000,
+        &(env.ledger().timestamp() + 86400),
+    );
+
+    (
+        client,
+        buyer,
+        seller,
+        arbiter,
+        token::Client::new(env, &token_address),
+    )
+}
+
+// Test 1: Buyer funds and then releases to the seller
+#[test]
+fn test_fund_and_release() {
+    let env = Env::default();
+    let (client, buyer, seller, _, token) = setup_escrow(&env, false);
+
+    client.fund(&buyer);
+    assert_eq!(client.get_status(), EscrowStatus::Funded as u32);
+    assert_eq!(token.balance(&client.address), 500_000_000);
+
+    client.release(&buyer);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "escrow_released"),
+            seller.clone()
+        )
+            .into_val(&env)
+    );
+
+    assert_eq!(token.balance(&seller), 500_000_000);
+    assert_eq!(client.get_status(), EscrowStatus::Released as u32);
+}
+
+// Test 2: The buyer gets the funds back once the deadline passes
+#[test]
+fn test_refund_on_timeout() {
+    let env = Env::default();
+    let (client, buyer, _, _, token) = setup_escrow(&env, false);
+
+    client.fund(&buyer);
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+    client.refund_on_timeout();
+
+    assert_eq!(token.balance(&buyer), 500_000_000);
+    assert_eq!(client.get_status(), EscrowStatus::Refunded as u32);
+}
+
+// Test 3: No refund before the deadline
+#[test]
+#[should_panic(expected = "Deadline has not passed")]
+fn test_refund_before_deadline() {
+    let env = Env::default();
+    let (client, buyer, _, _, _) = setup_escrow(&env, false);
+
+    client.fund(&buyer);
+    client.refund_on_timeout();
+}
+
+// Test 4: Only the buyer can release the funds
+#[test]
+#[should_panic(expected = "Only the buyer can release")]
+fn test_release_not_buyer() {
+    let env = Env::default();
+    let (client, buyer, seller, _, _) = setup_escrow(&env, false);
+
+    client.fund(&buyer);
+    client.release(&seller);
+}
+
+// Test 5: A dispute blocks the timeout refund until the arbiter pays the seller
+#[test]
+fn test_dispute_resolved_for_seller() {
+    let env = Env::default();
+    let (client, buyer, seller, arbiter, token) = setup_escrow(&env, true);
+
+    client.fund(&buyer);
+    client.dispute(&seller);
+    assert_eq!(client.get_status(), EscrowStatus::Disputed as u32);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+    assert!(client.try_refund_on_timeout().is_err());
+
+    client.resolve(&arbiter, &true);
+    assert_eq!(token.balance(&seller), 500_000_000);
+    assert_eq!(client.get_status(), EscrowStatus::Released as u32);
+}
+
+// Test 6: The arbiter can refund the buyer
+#[test]
+fn test_dispute_resolved_for_buyer() {
+    let env = Env::default();
+    let (client, buyer, _, arbiter, token) = setup_escrow(&env, true);
+
+    client.fund(&buyer);
+    client.dispute(&buyer);
+    client.resolve(&arbiter, &false);
+
+    assert_eq!(token.balance(&buyer), 500_000_000);
+    assert_eq!(client.get_status(), EscrowStatus::Refunded as u32);
+}
+
+// Test 7: Disputes need an arbiter
+#[test]
+#[should_panic(expected = "Escrow has no arbiter")]
+fn test_dispute_without_arbiter() {
+    let env = Env::default();
+    let (client, buyer, _, _, _) = setup_escrow(&env, false);
+
+    client.fund(&buyer);
+    client.dispute(&buyer);
+}
+
+// Test 8: Only the arbiter can resolve
+#[test]
+#[should_panic(expected = "Only the arbiter can resolve disputes")]
+fn test_resolve_not_arbiter() {
+    let env = Env::default();
+    let (client, buyer, seller, _, _) = setup_escrow(&env, true);
+
+    client.fund(&buyer);
+    client.dispute(&buyer);
+    client.resolve(&seller, &true);
+}
+
+// Test 9: The arbiter can't be one of the parties
+#[test]
+#[should_panic(expected = "Arbiter must be neither the buyer nor the seller")]
+fn test_arbiter_is_seller() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = EscrowContractClient::new(&env, &env.register(EscrowContract, ()));
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    client.initialize(
+        &buyer,
+        &seller,
+        &Some(seller.clone()),
+        &Address::generate(&env),
+        &500_000_000,
+        &(env.ledger().timestamp() + 86400),
+    );
+}
+
+// Test 10: A dispute the arbiter never settles is refunded after the timeout
+#[test]
+fn test_dispute_timeout_refunds_buyer() {
+    let env = Env::default();
+    let (client, buyer, seller, _, token) = setup_escrow(&env, true);
+
+    client.fund(&buyer);
+    client.dispute(&seller);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += DISPUTE_TIMEOUT;
+    });
+    assert!(client.try_refund_on_timeout().is_err());
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 1;
+    });
+    client.refund_on_timeout();
+    assert_eq!(token.balance(&buyer), 500_000_000);
+    assert_eq!(client.get_status(), EscrowStatus::Refunded as u32);
+}
@@ -0,0 +1,498 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contractevent, contractimpl, contracttype, symbol_short, token, Address, Env, String,
+    Symbol, Vec,
+};
+
+// Storage keys for our contract data
+const FUNDER: Symbol = symbol_short!("funder");
+const TOKEN_ADDRESS: Symbol = symbol_short!("token");
+const COMMITTEE: Symbol = symbol_short!("committee");
+const THRESHOLD: Symbol = symbol_short!("threshold");
+const AVAILABLE: Symbol = symbol_short!("available");
+const RESERVED: Symbol = symbol_short!("reserved");
+const TOTAL_PAID: Symbol = symbol_short!("paid");
+const APPLICATION_COUNT: Symbol = symbol_short!("app_cnt");
+const IS_ALREADY_INIT: Symbol = symbol_short!("is_init");
+
+// Length limits (in bytes) for application text
+pub const MAX_TITLE_LEN: u32 = 100;
+pub const MAX_URI_LEN: u32 = 256;
+
+// Reviews score applications from 0 to this
+pub const MAX_SCORE: u32 = 10;
+
+// Grants pay out in at most this many milestones
+pub const MAX_MILESTONES: u32 = 12;
+
+// Application status enum
+#[derive(Clone, Copy, PartialEq)]
+pub enum ApplicationStatus {
+    Pending = 0,   // Under committee review
+    Approved = 1,  // Funds reserved, milestones being paid
+    Rejected = 2,  // Not enough approvals possible any more
+    Completed = 3, // Every milestone paid
+    Cancelled = 4, // Stopped by the funder; unpaid funds back in the budget
+}
+
+// Keys for per-entry data kept in persistent storage
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Application(u32),           // Application, by sequential ID
+    Review(u32, Address),       // Reviewer's review of an application
+    Signoff(u32, u32, Address), // Member's sign-off on an application's milestone
+}
+
+// A grant application and where it stands
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Application {
+    pub id: u32,
+    pub applicant: Address,
+    pub title: String,
+    pub requested: i128,
+    pub uri: String,     // Full proposal
+    pub milestones: u32, // Number of equal payouts (1 pays on approval)
+    pub milestones_paid: u32,
+    pub signoffs: u32, // Committee sign-offs on the next milestone
+    pub paid: i128,
+    pub status: u32, // ApplicationStatus as u32
+    pub approvals: u32,
+    pub rejections: u32,
+    pub score_total: u32, // Sum of review scores (average = score_total / reviews)
+    pub submitted_at: u64,
+}
+
+// One committee member's review
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Review {
+    pub score: u32,
+    pub approve: bool,
+    pub reviewed_at: u64,
+}
+
+// Emitted when an application is submitted
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationSubmitted {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub applicant: Address,
+    pub requested: i128,
+}
+
+// Emitted for every committee review
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationReviewed {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub reviewer: Address,
+    pub score: u32,
+    pub approve: bool,
+}
+
+// Emitted when an application's status changes
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationStatusChanged {
+    #[topic]
+    pub id: u32,
+    pub status: u32,
+}
+
+// Emitted for every grant payout
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantPaid {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub applicant: Address,
+    pub milestone: u32,
+    pub amount: i128,
+}
+
+// A grant program: a funder deposits a budget, applicants submit proposals, and
+// a committee reviews them on chain. Approved grants are paid from the budget
+#[contract]
+pub struct GrantsContract;
+
+#[contractimpl]
+impl GrantsContract {
+    // Set up the program. `threshold` committee approvals are needed for a grant
+    pub fn initialize(
+        env: Env,
+        funder: Address,
+        token: Address,
+        committee: Vec<Address>,
+        threshold: u32,
+    ) {
+        // Verify the funder is who they claim to be
+        funder.require_auth();
+
+        if env.storage().instance().has(&IS_ALREADY_INIT) {
+            panic!("Grants already initialized");
+        }
+        if committee.is_empty() {
+            panic!("Committee cannot be empty");
+        }
+        for (i, member) in committee.iter().enumerate() {
+            if committee.first_index_of(&member) != Some(i as u32) {
+                panic!("Duplicate committee member");
+            }
+        }
+        if threshold == 0 || threshold > committee.len() {
+            panic!("Invalid approval threshold");
+        }
+
+        env.storage().instance().set(&FUNDER, &funder);
+        env.storage().instance().set(&TOKEN_ADDRESS, &token);
+        env.storage().instance().set(&COMMITTEE, &committee);
+        env.storage().instance().set(&THRESHOLD, &threshold);
+        env.storage().instance().set(&AVAILABLE, &0i128);
+        env.storage().instance().set(&RESERVED, &0i128);
+        env.storage().instance().set(&TOTAL_PAID, &0i128);
+        env.storage().instance().set(&IS_ALREADY_INIT, &true);
+    }
+
+    // Add to the budget
+    pub fn deposit(env: Env, funder: Address, amount: i128) {
+        require_funder(&env, &funder);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        // Transfer tokens from the funder to this contract
+        let contract_address = env.current_contract_address();
+        grants_token(&env).transfer(&funder, &contract_address, &amount);
+
+        add(&env, &AVAILABLE, amount);
+    }
+
+    // Take back budget that isn't reserved for approved grants
+    pub fn withdraw_budget(env: Env, funder: Address, amount: i128) {
+        require_funder(&env, &funder);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+        if amount > get(&env, &AVAILABLE) {
+            panic!("Amount exceeds available budget");
+        }
+
+        add(&env, &AVAILABLE, -amount);
+        grants_token(&env).transfer(&env.current_contract_address(), &funder, &amount);
+    }
+
+    // Apply for a grant paid in `milestones` equal parts. Returns the application ID
+    pub fn submit_application(
+        env: Env,
+        applicant: Address,
+        title: String,
+        requested: i128,
+        uri: String,
+        milestones: u32,
+    ) -> u32 {
+        // Verify the applicant is authorized
+        applicant.require_auth();
+
+        if title.is_empty() || title.len() > MAX_TITLE_LEN {
+            panic!("Title must be 1 to 100 bytes");
+        }
+        if uri.len() > MAX_URI_LEN {
+            panic!("URI is too long");
+        }
+        if requested <= 0 {
+            panic!("Requested amount must be positive");
+        }
+        if milestones == 0 || milestones > MAX_MILESTONES {
+            panic!("Milestones must be between 1 and 12");
+        }
+
+        let id = Self::get_application_count(env.clone());
+        save(
+            &env,
+            &Application {
+                id,
+                applicant: applicant.clone(),
+                title,
+                requested,
+                uri,
+                milestones,
+                milestones_paid: 0,
+                signoffs: 0,
+                paid: 0,
+                status: ApplicationStatus::Pending as u32,
+                approvals: 0,
+                rejections: 0,
+                score_total: 0,
+                submitted_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().instance().set(&APPLICATION_COUNT, &(id + 1));
+
+        ApplicationSubmitted {
+            id,
+            applicant,
+            requested,
+        }
+        .publish(&env);
+
+        id
+    }
+
+    // Score an application and vote on it. Once enough members approve, the
+    // requested amount is reserved from the budget (single-milestone grants are
+    // paid straight away). Once approval can no longer be reached it is rejected
+    pub fn review(env: Env, reviewer: Address, id: u32, score: u32, approve: bool) {
+        require_committee(&env, &reviewer);
+
+        let mut application = Self::get_application(env.clone(), id);
+        if application.status != ApplicationStatus::Pending as u32 {
+            panic!("Application is not pending");
+        }
+        if score > MAX_SCORE {
+            panic!("Score must be between 0 and 10");
+        }
+        let key = DataKey::Review(id, reviewer.clone());
+        if env.storage().persistent().has(&key) {
+            panic!("Already reviewed");
+        }
+
+        env.storage().persistent().set(
+            &key,
+            &Review {
+                score,
+                approve,
+                reviewed_at: env.ledger().timestamp(),
+            },
+        );
+        application.score_total += score;
+        if approve {
+            application.approvals += 1;
+        } else {
+            application.rejections += 1;
+        }
+
+        ApplicationReviewed {
+            id,
+            reviewer,
+            score,
+            approve,
+        }
+        .publish(&env);
+
+        let threshold: u32 = env.storage().instance().get(&THRESHOLD).unwrap();
+        let committee_size = committee(&env).len();
+        if application.approvals >= threshold {
+            // Reserve the whole grant so later approvals can't overspend the budget
+            if application.requested > get(&env, &AVAILABLE) {
+                panic!("Budget exhausted");
+            }
+            add(&env, &AVAILABLE, -application.requested);
+            add(&env, &RESERVED, application.requested);
+            set_status(&env, &mut application, ApplicationStatus::Approved);
+
+            if application.milestones == 1 {
+                pay_milestone(&env, &mut application);
+            }
+        } else if application.rejections > committee_size - threshold {
+            set_status(&env, &mut application, ApplicationStatus::Rejected);
+        }
+
+        save(&env, &application);
+    }
+
+    // Sign off on the next milestone of an approved grant once the work has
+    // been checked. It is paid when as many members as approve a grant agree
+    pub fn release_milestone(env: Env, reviewer: Address, id: u32) {
+        require_committee(&env, &reviewer);
+
+        let mut application = Self::get_application(env.clone(), id);
+        if application.status != ApplicationStatus::Approved as u32 {
+            panic!("Grant is not approved");
+        }
+        let milestone = application.milestones_paid + 1;
+        let key = DataKey::Signoff(id, milestone, reviewer);
+        if env.storage().persistent().has(&key) {
+            panic!("Already signed off on this milestone");
+        }
+
+        env.storage().persistent().set(&key, &true);
+        application.signoffs += 1;
+
+        let threshold: u32 = env.storage().instance().get(&THRESHOLD).unwrap();
+        if application.signoffs >= threshold {
+            application.signoffs = 0;
+            pay_milestone(&env, &mut application);
+        }
+        save(&env, &application);
+    }
+
+    // Stop an approved grant. Whatever hasn't been paid goes back to the budget
+    pub fn cancel_grant(env: Env, funder: Address, id: u32) {
+        require_funder(&env, &funder);
+
+        let mut application = Self::get_application(env.clone(), id);
+        if application.status != ApplicationStatus::Approved as u32 {
+            panic!("Grant is not approved");
+        }
+
+        let unpaid = application.requested - application.paid;
+        add(&env, &RESERVED, -unpaid);
+        add(&env, &AVAILABLE, unpaid);
+        set_status(&env, &mut application, ApplicationStatus::Cancelled);
+        save(&env, &application);
+    }
+
+    // Get an application by ID
+    pub fn get_application(env: Env, id: u32) -> Application {
+        match env.storage().persistent().get(&DataKey::Application(id)) {
+            Some(application) => application,
+            None => panic!("Application not found"),
+        }
+    }
+
+    // Get up to `limit` applications starting at `offset`, oldest first
+    pub fn get_applications(env: Env, offset: u32, limit: u32) -> Vec<Application> {
+        let mut applications = Vec::new(&env);
+        let end = offset
+            .saturating_add(limit)
+            .min(Self::get_application_count(env.clone()));
+
+        for id in offset..end {
+            applications.push_back(Self::get_application(env.clone(), id));
+        }
+
+        applications
+    }
+
+    // Get how many applications have been submitted
+    pub fn get_application_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&APPLICATION_COUNT)
+            .unwrap_or(0)
+    }
+
+    // Get a committee member's review of an application, if they reviewed it
+    pub fn get_review(env: Env, id: u32, reviewer: Address) -> Option<Review> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Review(id, reviewer))
+    }
+
+    // Get the budget (available, reserved for approved grants, paid out)
+    pub fn get_budget(env: Env) -> (i128, i128, i128) {
+        (
+            get(&env, &AVAILABLE),
+            get(&env, &RESERVED),
+            get(&env, &TOTAL_PAID),
+        )
+    }
+
+    // Get the committee and how many approvals a grant needs
+    pub fn get_committee(env: Env) -> (Vec<Address>, u32) {
+        (
+            committee(&env),
+            env.storage().instance().get(&THRESHOLD).unwrap(),
+        )
+    }
+}
+
+fn require_funder(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    let funder: Address = match env.storage().instance().get(&FUNDER) {
+        Some(funder) => funder,
+        None => panic!("Grants not initialized"),
+    };
+    if *caller != funder {
+        panic!("Only the funder can perform this action");
+    }
+}
+
+fn require_committee(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    if !committee(env).contains(caller) {
+        panic!("Only committee members can perform this action");
+    }
+}
+
+fn committee(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&COMMITTEE)
+        .unwrap_or(Vec::new(env))
+}
+
+fn grants_token(env: &Env) -> token::Client<'_> {
+    let token_address: Address = env.storage().instance().get(&TOKEN_ADDRESS).unwrap();
+    token::Client::new(env, &token_address)
+}
+
+fn get(env: &Env, key: &Symbol) -> i128 {
+    env.storage().instance().get(key).unwrap_or(0)
+}
+
+fn add(env: &Env, key: &Symbol, amount: i128) {
+    env.storage().instance().set(key, &(get(env, key) + amount));
+}
+
+fn save(env: &Env, application: &Application) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Application(application.id), application);
+}
+
+fn set_status(env: &Env, application: &mut Application, status: ApplicationStatus) {
+    application.status = status as u32;
+    ApplicationStatusChanged {
+        id: application.id,
+        status: status as u32,
+    }
+    .publish(env);
+}
+
+// Pay the next milestone out of the grant's reservation. The last milestone
+// pays whatever rounding left over
+fn pay_milestone(env: &Env, application: &mut Application) {
+    let milestone = application.milestones_paid + 1;
+    let amount = if milestone == application.milestones {
+        application.requested - application.paid
+    } else {
+        application.requested / application.milestones as i128
+    };
+
+    application.milestones_paid = milestone;
+    application.paid += amount;
+    add(env, &RESERVED, -amount);
+    add(env, &TOTAL_PAID, amount);
+    if milestone == application.milestones {
+        set_status(env, application, ApplicationStatus::Completed);
+    }
+
+    grants_token(env).transfer(
+        &env.current_contract_address(),
+        &application.applicant,
+        &amount,
+    );
+
+    GrantPaid {
+        id: application.id,
+        applicant: application.applicant.clone(),
+        milestone,
+        amount,
+    }
+    .publish(env);
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,212 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, testutils::Events, token, Address, Env, IntoVal};
+
+// Helper: a program with a 100 XLM budget and a committee of three needing two approvals
+fn setup_grants<'a>(
+    env: &Env,
+) -> (
+    GrantsContractClient<'a>,
+    Address,
+    Vec<Address>,
+    token::Client<'a>,
+) {
+    let contract_id = env.register(GrantsContract, ());
+    let client = GrantsContractClient::new(env, &contract_id);
+
+    let funder = Address::generate(env);
+    let committee = soroban_sdk::vec![
+        env,
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env)
+    ];
+    let token_address = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    env.mock_all_auths();
+
+    token::StellarAssetClient::new(env, &token_address).mint(&funder, &1_000_000_000);
+    client.initialize(&funder, &token_address, &committee, &2);
+    client.deposit(&funder, &1_000_000_000);
+
+    (
+        client,
+        funder,
+        committee,
+        token::Client::new(env, &token_address),
+    )
+}
+
+// Helper: submit an application from a fresh applicant
+fn apply(
+    env: &Env,
+    client: &GrantsContractClient,
+    requested: i128,
+    milestones: u32,
+) -> (u32, Address) {
+    let applicant = Address::generate(env);
+    let id = client.submit_application(
+        &applicant,
+        &String::from_str(env, "Indonesian wallet docs"),
+        &requested,
+        &String::from_str(env, "ipfs://proposal"),
+        &milestones,
+    );
+    (id, applicant)
+}
+
+// Test 1: Two approvals pay a single-milestone grant straight away
+#[test]
+fn test_grant_approved_and_paid() {
+    let env = Env::default();
+    let (client, _, committee, token) = setup_grants(&env);
+
+    let (id, applicant) = apply(&env, &client, 300_000_000, 1);
+    client.review(&committee.get(0).unwrap(), &id, &8, &true);
+    assert_eq!(
+        client.get_application(&id).status,
+        ApplicationStatus::Pending as u32
+    );
+
+    client.review(&committee.get(1).unwrap(), &id, &6, &true);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (
+            soroban_sdk::Symbol::new(&env, "grant_paid"),
+            id,
+            applicant.clone()
+        )
+            .into_val(&env)
+    );
+
+    let application = client.get_application(&id);
+    assert_eq!(application.status, ApplicationStatus::Completed as u32);
+    assert_eq!(application.score_total, 14);
+    assert_eq!(token.balance(&applicant), 300_000_000);
+    assert_eq!(client.get_budget(), (700_000_000, 0, 300_000_000));
+}
+
+// Test 2: Milestone grants reserve the budget and pay out part by part
+#[test]
+fn test_grant_milestones() {
+    let env = Env::default();
+    let (client, _, committee, token) = setup_grants(&env);
+
+    let (id, applicant) = apply(&env, &client, 100_000_001, 3);
+    client.review(&committee.get(0).unwrap(), &id, &9, &true);
+    client.review(&committee.get(2).unwrap(), &id, &7, &true);
+    assert_eq!(client.get_budget(), (899_999_999, 100_000_001, 0));
+
+    // Each milestone needs two sign-offs
+    client.release_milestone(&committee.get(1).unwrap(), &id);
+    assert_eq!(token.balance(&applicant), 0);
+    assert_eq!(client.get_application(&id).signoffs, 1);
+    client.release_milestone(&committee.get(2).unwrap(), &id);
+    client.release_milestone(&committee.get(1).unwrap(), &id);
+    client.release_milestone(&committee.get(0).unwrap(), &id);
+    assert_eq!(token.balance(&applicant), 66_666_666);
+
+    // The last milestone picks up the rounding
+    client.release_milestone(&committee.get(0).unwrap(), &id);
+    client.release_milestone(&committee.get(2).unwrap(), &id);
+    assert_eq!(token.balance(&applicant), 100_000_001);
+    assert_eq!(
+        client.get_application(&id).status,
+        ApplicationStatus::Completed as u32
+    );
+}
+
+// Test 3: Enough rejections close the application
+#[test]
+fn test_grant_rejected() {
+    let env = Env::default();
+    let (client, _, committee, _) = setup_grants(&env);
+
+    let (id, _) = apply(&env, &client, 100_000_000, 1);
+    client.review(&committee.get(0).unwrap(), &id, &2, &false);
+    client.review(&committee.get(1).unwrap(), &id, &3, &false);
+
+    assert_eq!(
+        client.get_application(&id).status,
+        ApplicationStatus::Rejected as u32
+    );
+    let review = client.get_review(&id, &committee.get(1).unwrap()).unwrap();
+    assert_eq!(review.score, 3);
+}
+
+// Test 4: Approvals stop once the budget is used up
+#[test]
+#[should_panic(expected = "Budget exhausted")]
+fn test_grant_over_budget() {
+    let env = Env::default();
+    let (client, _, committee, _) = setup_grants(&env);
+
+    let (first, _) = apply(&env, &client, 800_000_000, 2);
+    let (second, _) = apply(&env, &client, 300_000_000, 1);
+    client.review(&committee.get(0).unwrap(), &first, &8, &true);
+    client.review(&committee.get(1).unwrap(), &first, &8, &true);
+
+    client.review(&committee.get(0).unwrap(), &second, &8, &true);
+    client.review(&committee.get(1).unwrap(), &second, &8, &true);
+}
+
+// Test 5: Cancelling a grant returns what wasn't paid to the budget
+#[test]
+fn test_cancel_grant() {
+    let env = Env::default();
+    let (client, funder, committee, token) = setup_grants(&env);
+
+    let (id, _) = apply(&env, &client, 200_000_000, 2);
+    client.review(&committee.get(0).unwrap(), &id, &8, &true);
+    client.review(&committee.get(1).unwrap(), &id, &8, &true);
+    client.release_milestone(&committee.get(0).unwrap(), &id);
+    client.release_milestone(&committee.get(1).unwrap(), &id);
+
+    client.cancel_grant(&funder, &id);
+    assert_eq!(client.get_budget(), (900_000_000, 0, 100_000_000));
+
+    client.withdraw_budget(&funder, &900_000_000);
+    assert_eq!(token.balance(&funder), 900_000_000);
+}
+
+// Test 6: Each committee member reviews an application once
+#[test]
+#[should_panic(expected = "Already reviewed")]
+fn test_review_twice() {
+    let env = Env::default();
+    let (client, _, committee, _) = setup_grants(&env);
+
+    let (id, _) = apply(&env, &client, 100_000_000, 1);
+    client.review(&committee.get(0).unwrap(), &id, &8, &true);
+    client.review(&committee.get(0).unwrap(), &id, &8, &true);
+}
+
+// Test 7: Only committee members can review
+#[test]
+#[should_panic(expected = "Only committee members can perform this action")]
+fn test_review_not_committee() {
+    let env = Env::default();
+    let (client, _, _, _) = setup_grants(&env);
+
+    let (id, applicant) = apply(&env, &client, 100_000_000, 1);
+    client.review(&applicant, &id, &10, &true);
+}
+
+// Test 8: A single member can't release a milestone by signing off twice
+#[test]
+#[should_panic(expected = "Already signed off on this milestone")]
+fn test_milestone_signoff_twice() {
+    let env = Env::default();
+    let (client, _, committee, _) = setup_grants(&env);
+
+    let (id, _) = apply(&env, &client, 200_000_000, 2);
+    client.review(&committee.get(0).unwrap(), &id, &8, &true);
+    client.review(&committee.get(1).unwrap(), &id, &8, &true);
+
+    client.release_milestone(&committee.get(0).unwrap(), &id);
+    client.release_milestone(&committee.get(0).unwrap(), &id);
+}
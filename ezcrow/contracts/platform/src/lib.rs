@@ -0,0 +1,99 @@
+#![no_std]
+use soroban_sdk::{contract, contractevent, contractimpl, symbol_short, Address, Env, Map, Symbol};
+
+// Storage keys for our contract data
+const PLATFORM_ADMIN: Symbol = symbol_short!("admin");
+const PAUSED: Symbol = symbol_short!("paused");
+
+// Operations that can be paused. Campaign contracts use the same numbering
+#[derive(Clone, Copy, PartialEq)]
+pub enum Operation {
+    Donate = 0,
+    Withdraw = 1,
+}
+
+// Emitted whenever the admin pauses or resumes an operation platform-wide
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseChanged {
+    #[topic]
+    pub operation: u32,
+    pub paused: bool,
+}
+
+// Shared platform settings that every campaign can consult
+#[contract]
+pub struct PlatformContract;
+
+#[contractimpl]
+impl PlatformContract {
+    // Set up the platform with its admin
+    pub fn initialize(env: Env, admin: Address) {
+        // Verify the admin is who they claim to be
+        admin.require_auth();
+
+        if env.storage().instance().has(&PLATFORM_ADMIN) {
+            panic!("Platform already initialized");
+        }
+
+        env.storage().instance().set(&PLATFORM_ADMIN, &admin);
+    }
+
+    // Hand the admin role to another address
+    pub fn set_admin(env: Env, admin: Address, new_admin: Address) {
+        require_admin(&env, &admin);
+        env.storage().instance().set(&PLATFORM_ADMIN, &new_admin);
+    }
+
+    // Pause or resume an operation across every campaign using this platform
+    pub fn set_paused(env: Env, admin: Address, operation: u32, paused: bool) {
+        require_admin(&env, &admin);
+
+        if operation > Operation::Withdraw as u32 {
+            panic!("Unknown operation");
+        }
+
+        let mut flags: Map<u32, bool> = env
+            .storage()
+            .instance()
+            .get(&PAUSED)
+            .unwrap_or(Map::new(&env));
+        flags.set(operation, paused);
+        env.storage().instance().set(&PAUSED, &flags);
+
+        PauseChanged { operation, paused }.publish(&env);
+    }
+
+    // Check whether an operation is paused platform-wide
+    pub fn is_paused(env: Env, operation: u32) -> bool {
+        let flags: Map<u32, bool> = env
+            .storage()
+            .instance()
+            .get(&PAUSED)
+            .unwrap_or(Map::new(&env));
+        flags.get(operation).unwrap_or(false)
+    }
+
+    // Get the platform admin
+    pub fn get_admin(env: Env) -> Address {
+        match env.storage().instance().get(&PLATFORM_ADMIN) {
+            Some(admin) => admin,
+            None => panic!("Platform not initialized"),
+        }
+    }
+}
+
+fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    let admin: Address = match env.storage().instance().get(&PLATFORM_ADMIN) {
+        Some(admin) => admin,
+        None => panic!("Platform not initialized"),
+    };
+    if *caller != admin {
+        panic!("Only platform admin can perform this action");
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+// Test 1: Admin pauses and resumes an operation
+#[test]
+fn test_set_paused() {
+    let env = Env::default();
+    let contract_id = env.register(PlatformContract, ());
+    let client = PlatformContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+    assert_eq!(client.get_admin(), admin);
+    assert!(!client.is_paused(&(Operation::Donate as u32)));
+
+    client.set_paused(&admin, &(Operation::Donate as u32), &true);
+    assert!(client.is_paused(&(Operation::Donate as u32)));
+    assert!(!client.is_paused(&(Operation::Withdraw as u32)));
+
+    client.set_paused(&admin, &(Operation::Donate as u32), &false);
+    assert!(!client.is_paused(&(Operation::Donate as u32)));
+}
+
+// Test 2: Only the admin can pause
+#[test]
+#[should_panic(expected = "Only platform admin can perform this action")]
+fn test_set_paused_not_admin() {
+    let env = Env::default();
+    let contract_id = env.register(PlatformContract, ());
+    let client = PlatformContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+    client.set_paused(&stranger, &(Operation::Withdraw as u32), &true);
+}
+
+// Test 3: The platform can only be initialized once
+#[test]
+#[should_panic(expected = "Platform already initialized")]
+fn test_initialize_twice() {
+    let env = Env::default();
+    let contract_id = env.register(PlatformContract, ());
+    let client = PlatformContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+    client.initialize(&Address::generate(&env));
+}
+
+// Test 4: The admin role can be handed over
+#[test]
+fn test_set_admin() {
+    let env = Env::default();
+    let contract_id = env.register(PlatformContract, ());
+    let client = PlatformContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.initialize(&admin);
+    client.set_admin(&admin, &new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+
+    client.set_paused(&new_admin, &(Operation::Withdraw as u32), &true);
+    assert!(client.is_paused(&(Operation::Withdraw as u32)));
+}
+
+// Test 5: Nobody can initialize the platform in the admin's name
+#[test]
+fn test_initialize_requires_admin_auth() {
+    let env = Env::default();
+    let contract_id = env.register(PlatformContract, ());
+    let client = PlatformContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+
+    assert!(client.try_initialize(&admin).is_err());
+    assert!(client.try_get_admin().is_err());
+}
@@ -0,0 +1,361 @@
+#![no_std]
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractclient, contractevent, contractimpl, contracttype, symbol_short, token,
+    Address, Env, IntoVal, Symbol, Vec,
+};
+
+mod qf;
+
+pub use qf::{allocate, isqrt, weight, BPS_DENOMINATOR};
+
+// Storage keys for our contract data
+const ROUND_ADMIN: Symbol = symbol_short!("admin");
+const TOKEN: Symbol = symbol_short!("token");
+const START_TIME: Symbol = symbol_short!("start");
+const END_TIME: Symbol = symbol_short!("end");
+const CAP_BPS: Symbol = symbol_short!("cap_bps");
+const MATCHING_POOL: Symbol = symbol_short!("pool");
+const CAMPAIGNS: Symbol = symbol_short!("campaigns");
+const FINALIZED: Symbol = symbol_short!("finalized");
+
+// Finalization pays every campaign in one call, so the round size is bounded
+pub const MAX_CAMPAIGNS: u32 = 50;
+
+// Campaign statuses that can receive matching
+const STATUS_SUCCESSFUL: u32 = 1;
+const STATUS_WITHDRAWN: u32 = 3;
+
+// Keys for per-campaign data kept in persistent storage
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Contribution(Address, Address), // (campaign, donor) -> donor's total through the round
+    Donors(Address),                // Donors who contributed to the campaign through the round
+    Raised(Address),                // Total contributed to the campaign through the round
+    Match(Address),                 // Matching paid to the campaign at finalization
+}
+
+// The parts of a crowdfunding campaign the round needs
+#[contractclient(name = "CampaignClient")]
+pub trait CampaignInterface {
+    fn donate(env: Env, donor: Address, amount: i128);
+    fn receive_match(env: Env, funder: Address, amount: i128);
+    fn get_donation(env: Env, donor: Address) -> i128;
+    fn get_campaign_status(env: Env) -> u32;
+}
+
+// Emitted for each campaign's matching when the round is finalized
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchPaid {
+    #[topic]
+    pub campaign: Address,
+    pub amount: i128,
+}
+
+// A quadratic funding round: donors give to registered campaigns during the
+// contribution window, and a shared matching pool is split by the QF formula
+#[contract]
+pub struct QfRoundContract;
+
+#[contractimpl]
+impl QfRoundContract {
+    // Set up the round. `cap_bps` is the most any one campaign can get of the
+    // matching pool, in basis points (10_000 = no cap)
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        token: Address,
+        start_time: u64,
+        end_time: u64,
+        cap_bps: u32,
+    ) {
+        // Verify the admin is who they claim to be
+        admin.require_auth();
+
+        if env.storage().instance().has(&ROUND_ADMIN) {
+            panic!("Round already initialized");
+        }
+        if start_time >= end_time {
+            panic!("Start time must be before end time");
+        }
+        if cap_bps == 0 || cap_bps as i128 > BPS_DENOMINATOR {
+            panic!("Cap must be between 1 and 10000 basis points");
+        }
+
+        env.storage().instance().set(&ROUND_ADMIN, &admin);
+        env.storage().instance().set(&TOKEN, &token);
+        env.storage().instance().set(&START_TIME, &start_time);
+        env.storage().instance().set(&END_TIME, &end_time);
+        env.storage().instance().set(&CAP_BPS, &cap_bps);
+        env.storage().instance().set(&MATCHING_POOL, &0i128);
+        env.storage()
+            .instance()
+            .set(&CAMPAIGNS, &Vec::<Address>::new(&env));
+        env.storage().instance().set(&FINALIZED, &false);
+    }
+
+    // Add funds to the matching pool. Anyone can fund it until the round is finalized
+    pub fn fund_pool(env: Env, funder: Address, amount: i128) {
+        // Verify the funder is authorized
+        funder.require_auth();
+        ensure_not_finalized(&env);
+
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        round_token(&env).transfer(&funder, env.current_contract_address(), &amount);
+        env.storage()
+            .instance()
+            .set(&MATCHING_POOL, &(matching_pool(&env) + amount));
+    }
+
+    // Register a crowdfunding campaign so it can receive contributions and matching
+    pub fn register_campaign(env: Env, admin: Address, campaign: Address) {
+        require_admin(&env, &admin);
+        ensure_not_finalized(&env);
+
+        let mut campaigns = campaigns(&env);
+        if campaigns.contains(&campaign) {
+            panic!("Campaign already registered");
+        }
+        if campaigns.len() >= MAX_CAMPAIGNS {
+            panic!("Too many campaigns");
+        }
+
+        campaigns.push_back(campaign);
+        env.storage().instance().set(&CAMPAIGNS, &campaigns);
+    }
+
+    // Contribute to a registered campaign during the window. The contribution is
+    // a regular donation to the campaign (with the usual refund rights) and also
+    // counts toward the campaign's share of the matching pool
+    pub fn contribute(env: Env, donor: Address, campaign: Address, amount: i128) {
+        // Verify the donor is authorized
+        donor.require_auth();
+
+        let now = env.ledger().timestamp();
+        let start: u64 = env.storage().instance().get(&START_TIME).unwrap();
+        let end: u64 = env.storage().instance().get(&END_TIME).unwrap();
+        if now < start || now > end {
+            panic!("Round is not open for contributions");
+        }
+        if !campaigns(&env).contains(&campaign) {
+            panic!("Campaign is not registered");
+        }
+        if amount <= 0 {
+            panic!("Amount must be positive");
+        }
+
+        // The campaign checks its own rules and takes the funds from the donor
+        CampaignClient::new(&env, &campaign).donate(&donor, &amount);
+
+        let key = DataKey::Contribution(campaign.clone(), donor.clone());
+        let previous: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(previous + amount));
+        if previous == 0 {
+            let mut donors = donors_of(&env, &campaign);
+            donors.push_back(donor);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Donors(campaign.clone()), &donors);
+        }
+
+        env.storage().persistent().set(
+            &DataKey::Raised(campaign.clone()),
+            &(Self::get_raised(env.clone(), campaign) + amount),
+        );
+    }
+
+    // Split the matching pool once the window has closed and pay it into each
+    // successful campaign, where it is released with the rest of the funds.
+    // Campaigns that didn't succeed get nothing. Anyone can call it, once
+    pub fn finalize(env: Env) {
+        ensure_not_finalized(&env);
+
+        let end: u64 = env.storage().instance().get(&END_TIME).unwrap();
+        if env.ledger().timestamp() <= end {
+            panic!("Round is still open");
+        }
+
+        env.storage().instance().set(&FINALIZED, &true);
+
+        let campaigns = campaigns(&env);
+        let mut weights = Vec::new(&env);
+        for campaign in campaigns.iter() {
+            let status = CampaignClient::new(&env, &campaign).get_campaign_status();
+            if status == STATUS_SUCCESSFUL || status == STATUS_WITHDRAWN {
+                weights.push_back(weight(held_sqrt_sum(&env, &campaign)));
+            } else {
+                weights.push_back(0);
+            }
+        }
+        let cap_bps: u32 = env.storage().instance().get(&CAP_BPS).unwrap();
+        let allocations = allocate(&env, matching_pool(&env), cap_bps, &weights);
+
+        for (campaign, amount) in campaigns.iter().zip(allocations.iter()) {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Match(campaign.clone()), &amount);
+            if amount > 0 {
+                pay_match(&env, &campaign, amount);
+                MatchPaid { campaign, amount }.publish(&env);
+            }
+        }
+    }
+
+    // Send whatever the allocation left unpaid (rounding dust, or the excess
+    // when the cap held everyone back) back to the admin after finalization
+    pub fn withdraw_leftover(env: Env, admin: Address) -> i128 {
+        require_admin(&env, &admin);
+
+        if !Self::is_finalized(env.clone()) {
+            panic!("Round is not finalized");
+        }
+
+        let token = round_token(&env);
+        let leftover = token.balance(&env.current_contract_address());
+        if leftover > 0 {
+            token.transfer(&env.current_contract_address(), &admin, &leftover);
+        }
+        leftover
+    }
+
+    // Get the matching a campaign would receive if the round ended now and
+    // every campaign succeeded
+    pub fn get_estimated_match(env: Env, campaign: Address) -> i128 {
+        let campaigns = campaigns(&env);
+        let index = match campaigns.first_index_of(&campaign) {
+            Some(index) => index,
+            None => panic!("Campaign is not registered"),
+        };
+
+        let mut weights = Vec::new(&env);
+        for campaign in campaigns.iter() {
+            weights.push_back(weight(held_sqrt_sum(&env, &campaign)));
+        }
+        let cap_bps: u32 = env.storage().instance().get(&CAP_BPS).unwrap();
+        allocate(&env, matching_pool(&env), cap_bps, &weights)
+            .get(index)
+            .unwrap()
+    }
+
+    // Get the matching paid to a campaign at finalization
+    pub fn get_match(env: Env, campaign: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Match(campaign))
+            .unwrap_or(0)
+    }
+
+    // Get a donor's total contribution to a campaign through the round
+    pub fn get_contribution(env: Env, campaign: Address, donor: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Contribution(campaign, donor))
+            .unwrap_or(0)
+    }
+
+    // Get the total contributed to a campaign through the round
+    pub fn get_raised(env: Env, campaign: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Raised(campaign))
+            .unwrap_or(0)
+    }
+
+    // Get the registered campaigns
+    pub fn get_campaigns(env: Env) -> Vec<Address> {
+        campaigns(&env)
+    }
+
+    // Get the size of the matching pool
+    pub fn get_matching_pool(env: Env) -> i128 {
+        matching_pool(&env)
+    }
+
+    // Check whether the round has been finalized
+    pub fn is_finalized(env: Env) -> bool {
+        env.storage().instance().get(&FINALIZED).unwrap_or(false)
+    }
+}
+
+fn require_admin(env: &Env, caller: &Address) {
+    caller.require_auth();
+
+    let admin: Address = match env.storage().instance().get(&ROUND_ADMIN) {
+        Some(admin) => admin,
+        None => panic!("Round not initialized"),
+    };
+    if *caller != admin {
+        panic!("Only round admin can perform this action");
+    }
+}
+
+fn ensure_not_finalized(env: &Env) {
+    let finalized: bool = env.storage().instance().get(&FINALIZED).unwrap_or(false);
+    if finalized {
+        panic!("Round already finalized");
+    }
+}
+
+fn round_token(env: &Env) -> token::Client<'_> {
+    let address: Address = env.storage().instance().get(&TOKEN).unwrap();
+    token::Client::new(env, &address)
+}
+
+fn campaigns(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&CAMPAIGNS)
+        .unwrap_or(Vec::new(env))
+}
+
+fn matching_pool(env: &Env) -> i128 {
+    env.storage().instance().get(&MATCHING_POOL).unwrap_or(0)
+}
+
+fn donors_of(env: &Env, campaign: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Donors(campaign.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+// Sum of the square roots of what each donor's round contributions still left
+// in the campaign. Anything refunded or withdrawn no longer counts
+fn held_sqrt_sum(env: &Env, campaign: &Address) -> i128 {
+    let client = CampaignClient::new(env, campaign);
+    let mut sum = 0i128;
+    for donor in donors_of(env, campaign).iter() {
+        let contribution: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contribution(campaign.clone(), donor.clone()))
+            .unwrap();
+        sum += isqrt(contribution.min(client.get_donation(&donor)));
+    }
+    sum
+}
+
+// Pay matching into the campaign, which takes it from the round
+fn pay_match(env: &Env, campaign: &Address, amount: i128) {
+    let round = env.current_contract_address();
+    env.authorize_as_current_contract(soroban_sdk::vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: round_token(env).address.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (round.clone(), campaign.clone(), amount).into_val(env),
+            },
+            sub_invocations: Vec::new(env),
+        }),
+    ]);
+    CampaignClient::new(env, campaign).receive_match(&round, &amount);
+}
+
+#[cfg(test)]
+mod test;
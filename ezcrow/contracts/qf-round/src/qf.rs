@@ -0,0 +1,85 @@
+use soroban_sdk::{Env, Vec};
+
+// Shares of the matching pool are in basis points: 10_000 is the whole pool
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+// Integer square root, rounded down. Contributions are square-rooted in
+// stroops, so rounding loses at most one stroop's worth of weight per donor
+pub fn isqrt(n: i128) -> i128 {
+    if n <= 0 {
+        return 0;
+    }
+
+    // Newton's method from an initial guess that is never below the root
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+// Quadratic funding weight of a campaign: the sum of its donors' square roots, squared
+pub fn weight(sqrt_sum: i128) -> i128 {
+    sqrt_sum * sqrt_sum
+}
+
+// Split `pool` across campaigns in proportion to their weights, with no campaign
+// receiving more than `cap_bps` of the pool. Whatever a capped campaign can't
+// take is shared again among the others until nobody is over the cap. Rounding
+// dust (and the excess when every campaign is capped) is left unallocated
+pub fn allocate(env: &Env, pool: i128, cap_bps: u32, weights: &Vec<i128>) -> Vec<i128> {
+    let cap = pool * cap_bps as i128 / BPS_DENOMINATOR;
+    let mut allocations = Vec::new(env);
+    let mut capped = Vec::new(env);
+    for _ in 0..weights.len() {
+        allocations.push_back(0i128);
+        capped.push_back(false);
+    }
+
+    let mut remaining = pool;
+    loop {
+        let mut total_weight = 0i128;
+        for i in 0..weights.len() {
+            if !capped.get(i).unwrap() {
+                total_weight += weights.get(i).unwrap();
+            }
+        }
+        if total_weight == 0 {
+            break;
+        }
+
+        // Cap everyone whose proportional share is too big, then share again
+        let mut newly_capped = false;
+        for i in 0..weights.len() {
+            if capped.get(i).unwrap() {
+                continue;
+            }
+            let share = remaining * weights.get(i).unwrap() / total_weight;
+            if share > cap {
+                allocations.set(i, cap);
+                capped.set(i, true);
+                newly_capped = true;
+            }
+        }
+        if newly_capped {
+            remaining = pool;
+            for i in 0..weights.len() {
+                if capped.get(i).unwrap() {
+                    remaining -= cap;
+                }
+            }
+            continue;
+        }
+
+        for i in 0..weights.len() {
+            if !capped.get(i).unwrap() {
+                allocations.set(i, remaining * weights.get(i).unwrap() / total_weight);
+            }
+        }
+        break;
+    }
+
+    allocations
+}
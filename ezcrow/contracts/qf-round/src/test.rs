@@ -0,0 +1,341 @@
+#![cfg(test)]
+extern crate std;
+
+use super::*;
+use crowdfunding::{CampaignFeatures, CrowdfundingContract, CrowdfundingContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+// Reference square root, computed in floating point and corrected to the floor
+fn reference_isqrt(n: i128) -> i128 {
+    let mut root = (n as f64).sqrt() as i128;
+    while root * root > n {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    root
+}
+
+// Reference allocation: proportional shares in floating point, capping the
+// largest share first and re-sharing the rest until nobody is over the cap
+fn reference_allocate(pool: i128, cap_bps: u32, weights: &[i128]) -> std::vec::Vec<f64> {
+    let cap = pool as f64 * cap_bps as f64 / 10_000.0;
+    let mut allocations = std::vec![0.0; weights.len()];
+    let mut capped = std::vec![false; weights.len()];
+
+    loop {
+        let remaining = pool as f64 - cap * capped.iter().filter(|c| **c).count() as f64;
+        let total: f64 = (0..weights.len())
+            .filter(|i| !capped[*i])
+            .map(|i| weights[i] as f64)
+            .sum();
+        if total == 0.0 {
+            return allocations;
+        }
+
+        let over = (0..weights.len())
+            .filter(|i| !capped[*i])
+            .max_by(|a, b| weights[*a].cmp(&weights[*b]))
+            .filter(|i| remaining * weights[*i] as f64 / total > cap);
+        match over {
+            Some(i) => {
+                allocations[i] = cap;
+                capped[i] = true;
+            }
+            None => {
+                for i in 0..weights.len() {
+                    if !capped[i] {
+                        allocations[i] = remaining * weights[i] as f64 / total;
+                    }
+                }
+                return allocations;
+            }
+        }
+    }
+}
+
+// Deterministic pseudo-random numbers so the comparisons are reproducible
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % bound
+    }
+}
+
+// Helper: a round with a funded matching pool, open from now for a day
+fn setup_round<'a>(
+    env: &Env,
+    pool: i128,
+    cap_bps: u32,
+) -> (
+    QfRoundContractClient<'a>,
+    Address,
+    token::StellarAssetClient<'a>,
+) {
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(env);
+    let token_address = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_admin = token::StellarAssetClient::new(env, &token_address);
+
+    let contract_id = env.register(QfRoundContract, ());
+    let client = QfRoundContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    let now = env.ledger().timestamp();
+    client.initialize(&admin, &token_address, &now, &(now + 86400), &cap_bps);
+
+    let funder = Address::generate(env);
+    token_admin.mint(&funder, &pool);
+    client.fund_pool(&funder, &pool);
+
+    (client, admin, token_admin)
+}
+
+// Helper: a registered crowdfunding campaign using the round's token, with a
+// 4 XLM goal
+fn add_campaign<'a>(
+    env: &Env,
+    round: &QfRoundContractClient,
+    admin: &Address,
+    token_admin: &token::StellarAssetClient,
+) -> (CrowdfundingContractClient<'a>, Address) {
+    let contract_id = env.register(CrowdfundingContract, ());
+    let campaign = CrowdfundingContractClient::new(env, &contract_id);
+    let owner = Address::generate(env);
+    let platform = env.register(platform::PlatformContract, ());
+    platform::PlatformContractClient::new(env, &platform).initialize(&Address::generate(env));
+    campaign.initialize(
+        &owner,
+        &40_000_000,
+        &(env.ledger().timestamp() + 2 * 86400),
+        &token_admin.address,
+        &soroban_sdk::String::from_str(env, "QF Campaign"),
+        &soroban_sdk::String::from_str(env, "A campaign in the round"),
+        &soroban_sdk::String::from_str(env, "https://example.com/image.jpg"),
+        &1_000_000,
+        &platform,
+        &CampaignFeatures::default(),
+    );
+    round.register_campaign(admin, &contract_id);
+    (campaign, owner)
+}
+
+// Helper: mint tokens to a fresh donor and contribute them through the round
+fn contribute_from_new_donor(
+    env: &Env,
+    round: &QfRoundContractClient,
+    token_admin: &token::StellarAssetClient,
+    campaign: &Address,
+    amount: i128,
+) -> Address {
+    let donor = Address::generate(env);
+    token_admin.mint(&donor, &amount);
+    round.contribute(&donor, campaign, &amount);
+    donor
+}
+
+// Test 1: Integer square root matches the reference on edge cases and random values
+#[test]
+fn test_isqrt_matches_reference() {
+    for n in 0..1_000 {
+        assert_eq!(isqrt(n), reference_isqrt(n));
+    }
+    for root in [1i128, 3_162, 1_000_000, 3_037_000_499] {
+        assert_eq!(isqrt(root * root), root);
+        assert_eq!(isqrt(root * root - 1), root - 1);
+        assert_eq!(isqrt(root * root + 1), root);
+    }
+
+    let mut rng = Lcg(7);
+    for _ in 0..1_000 {
+        let n = rng.next(1 << 62) as i128 * rng.next(1 << 20) as i128;
+        assert_eq!(isqrt(n), reference_isqrt(n));
+    }
+}
+
+// Test 2: Allocations match the reference to within rounding on random rounds
+#[test]
+fn test_allocate_matches_reference() {
+    let env = Env::default();
+    let mut rng = Lcg(42);
+
+    for _ in 0..200 {
+        let campaigns = 1 + rng.next(10) as usize;
+        let pool = 1 + rng.next(1_000_000_000_000) as i128;
+        let cap_bps = 1 + rng.next(10_000) as u32;
+
+        let mut weights = std::vec::Vec::new();
+        let mut soroban_weights = Vec::new(&env);
+        for _ in 0..campaigns {
+            let sqrt_sum = rng.next(10_000_000) as i128;
+            weights.push(weight(sqrt_sum));
+            soroban_weights.push_back(weight(sqrt_sum));
+        }
+
+        let allocations = allocate(&env, pool, cap_bps, &soroban_weights);
+        let expected = reference_allocate(pool, cap_bps, &weights);
+
+        // Each capped share is rounded down, which can pass up to one stroop
+        // per campaign on to the others
+        let tolerance = campaigns as f64;
+        let mut paid = 0;
+        for (i, expected) in expected.iter().enumerate() {
+            let allocation = allocations.get(i as u32).unwrap();
+            assert!((allocation as f64 - expected).abs() <= tolerance);
+            paid += allocation;
+        }
+        assert!(paid <= pool);
+    }
+}
+
+// Test 3: The cap holds a dominant campaign back and the rest is shared out
+#[test]
+fn test_allocate_cap() {
+    let env = Env::default();
+    let weights = soroban_sdk::vec![&env, 100, 1, 1];
+
+    let allocations = allocate(&env, 1_000, 5_000, &weights);
+    assert_eq!(allocations, soroban_sdk::vec![&env, 500, 250, 250]);
+
+    // With no cap the shares are purely proportional
+    let allocations = allocate(&env, 1_020, 10_000, &weights);
+    assert_eq!(allocations, soroban_sdk::vec![&env, 1_000, 10, 10]);
+}
+
+// Test 4: Broad support earns more matching than one large donor
+#[test]
+fn test_round_finalize_pays_matches() {
+    let env = Env::default();
+    let (round, admin, token_admin) = setup_round(&env, 1_000_000_000, 10_000);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let (broad, broad_owner) = add_campaign(&env, &round, &admin, &token_admin);
+    let (narrow, narrow_owner) = add_campaign(&env, &round, &admin, &token_admin);
+
+    // Four donors of 1 XLM against one donor of 4 XLM
+    for _ in 0..4 {
+        contribute_from_new_donor(&env, &round, &token_admin, &broad.address, 10_000_000);
+    }
+    let whale = contribute_from_new_donor(&env, &round, &token_admin, &narrow.address, 40_000_000);
+
+    // Contributions are ordinary donations to the campaigns
+    assert_eq!(broad.get_total_raised(), 40_000_000);
+    assert_eq!(narrow.get_donation(&whale), 40_000_000);
+    assert_eq!(round.get_contribution(&narrow.address, &whale), 40_000_000);
+
+    // Weights are (4 * 3162)^2 and 6324^2, so broad gets 4/5 of the pool
+    assert_eq!(round.get_estimated_match(&broad.address), 800_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+    round.finalize();
+
+    assert!(round.is_finalized());
+    assert_eq!(round.get_match(&broad.address), 800_000_000);
+    assert_eq!(round.get_match(&narrow.address), 200_000_000);
+    assert_eq!(round.withdraw_leftover(&admin), 0);
+
+    // The matching is paid into the campaigns and released with their funds
+    assert_eq!(broad.get_total_raised(), 840_000_000);
+    broad.withdraw(&broad_owner);
+    assert_eq!(token.balance(&broad_owner), 840_000_000);
+    narrow.withdraw(&narrow_owner);
+    assert_eq!(token.balance(&narrow_owner), 240_000_000);
+}
+
+// Test 5: Matching held back by the cap goes back to the admin
+#[test]
+fn test_round_cap_leftover() {
+    let env = Env::default();
+    let (round, admin, token_admin) = setup_round(&env, 1_000_000_000, 2_500);
+    let token = token::Client::new(&env, &token_admin.address);
+
+    let (campaign, _) = add_campaign(&env, &round, &admin, &token_admin);
+    contribute_from_new_donor(&env, &round, &token_admin, &campaign.address, 40_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+    round.finalize();
+
+    assert_eq!(round.get_match(&campaign.address), 250_000_000);
+    assert_eq!(round.withdraw_leftover(&admin), 750_000_000);
+    assert_eq!(token.balance(&admin), 750_000_000);
+}
+
+// Test 6: Contributions are rejected once the window has closed
+#[test]
+#[should_panic(expected = "Round is not open for contributions")]
+fn test_contribute_after_window() {
+    let env = Env::default();
+    let (round, admin, token_admin) = setup_round(&env, 1_000_000_000, 10_000);
+    let (campaign, _) = add_campaign(&env, &round, &admin, &token_admin);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+
+    contribute_from_new_donor(&env, &round, &token_admin, &campaign.address, 10_000_000);
+}
+
+// Test 7: Only registered campaigns can receive contributions
+#[test]
+#[should_panic(expected = "Campaign is not registered")]
+fn test_contribute_unregistered_campaign() {
+    let env = Env::default();
+    let (round, _, token_admin) = setup_round(&env, 1_000_000_000, 10_000);
+
+    let stranger = env.register(CrowdfundingContract, ());
+    contribute_from_new_donor(&env, &round, &token_admin, &stranger, 10_000_000);
+}
+
+// Test 8: The round can't be finalized while it is still open
+#[test]
+#[should_panic(expected = "Round is still open")]
+fn test_finalize_while_open() {
+    let env = Env::default();
+    let (round, _, _) = setup_round(&env, 1_000_000_000, 10_000);
+
+    round.finalize();
+}
+
+// Test 9: Only successful campaigns are matched, and only on what donors left in
+#[test]
+fn test_round_matches_held_contributions() {
+    let env = Env::default();
+    let (round, admin, token_admin) = setup_round(&env, 1_000_000_000, 10_000);
+
+    let (funded, _) = add_campaign(&env, &round, &admin, &token_admin);
+    let (failed, _) = add_campaign(&env, &round, &admin, &token_admin);
+
+    // 2 of this donor's 3 XLM are taken back before the goal is reached
+    let donor = contribute_from_new_donor(&env, &round, &token_admin, &funded.address, 30_000_000);
+    funded.unpledge(&donor, &20_000_000);
+    contribute_from_new_donor(&env, &round, &token_admin, &funded.address, 30_000_000);
+    assert_eq!(round.get_contribution(&funded.address, &donor), 30_000_000);
+    contribute_from_new_donor(&env, &round, &token_admin, &failed.address, 10_000_000);
+
+    // Weights are (3162 + 5477)^2 and 3162^2 while either could still succeed
+    assert_eq!(round.get_estimated_match(&funded.address), 881_860_129);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400 + 1;
+    });
+    round.finalize();
+
+    assert_eq!(round.get_match(&funded.address), 1_000_000_000);
+    assert_eq!(round.get_match(&failed.address), 0);
+    assert_eq!(funded.get_total_raised(), 1_040_000_000);
+}